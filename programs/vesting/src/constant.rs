@@ -1 +1,6 @@
 pub const ANCHOR_DISCRIMINATOR_SIZE: usize = 8;
+pub const MAX_UNLOCK_CHECKPOINTS: usize = 64;
+pub const MAX_MULTISIG_SIGNERS: usize = 10;
+pub const MAX_PROPOSAL_ACCOUNTS: usize = 16;
+pub const MAX_PROPOSAL_DATA_LEN: usize = 512;
+pub const MAX_ADMIN_ACTION_DELAY: i64 = 30 * 24 * 60 * 60;
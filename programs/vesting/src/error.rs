@@ -20,4 +20,82 @@ pub enum CustomError {
     RevokeNotNeeded,
     #[msg("Treasury does not have enough tokens to complete the operation")]
     NotEnoughTokensInTreasury,
+    #[msg("Beneficiary account has already been revoked")]
+    AlreadyRevoked,
+    #[msg("Treasury holds no tokens above the outstanding obligation")]
+    NoSurplusToWithdraw,
+    #[msg("Arithmetic overflow in vesting calculation")]
+    MathOverflow,
+    #[msg("Vesting period must be positive and fit within the schedule")]
+    InvalidVestingPeriod,
+    #[msg("Cliff amount cannot exceed the total vesting amount")]
+    InvalidCliffAmount,
+    #[msg("Initial unlock and cliff amounts cannot exceed the total vesting amount")]
+    InvalidInitialUnlockAmount,
+    #[msg("Unlock checkpoints must be ordered, within the schedule and sum to the total amount")]
+    InvalidUnlockCheckpoints,
+    #[msg("Batch claim expects (vesting account, treasury, beneficiary account, mint, beneficiary token account) groups")]
+    InvalidBatchClaimAccounts,
+    #[msg("There is no pending admin proposal")]
+    NoPendingAdmin,
+    #[msg("Refund token account must be owned by the treasurer")]
+    InvalidRefundAccount,
+    #[msg("Multisig needs unique signers and a threshold between 1 and the signer count")]
+    InvalidMultisigConfig,
+    #[msg("Signer is not a member of the multisig")]
+    NotAMultisigSigner,
+    #[msg("Proposed instruction exceeds the allowed size")]
+    InvalidProposalInstruction,
+    #[msg("Signer has already approved this proposal")]
+    ProposalAlreadyApproved,
+    #[msg("Proposal has already been executed")]
+    ProposalAlreadyExecuted,
+    #[msg("Proposal does not have enough approvals")]
+    NotEnoughApprovals,
+    #[msg("Admin action delay must be between zero and thirty days")]
+    InvalidAdminActionDelay,
+    #[msg("Action must be queued and wait out the admin action delay")]
+    AdminActionNotQueued,
+    #[msg("Queued admin action does not match this instruction")]
+    AdminActionMismatch,
+    #[msg("Queued admin action is still within its delay")]
+    AdminActionNotReady,
+    #[msg("Vesting account is paused")]
+    VestingAccountIsPaused,
+    #[msg("Vesting account is not paused")]
+    VestingAccountNotPaused,
+    #[msg("Schedule has already fully vested")]
+    VestingAlreadyComplete,
+    #[msg("Beneficiary account is already suspended")]
+    AlreadySuspended,
+    #[msg("Beneficiary account is not suspended")]
+    NotSuspended,
+    #[msg("Amendment can only extend times and cannot change piecewise schedules")]
+    InvalidScheduleAmendment,
+    #[msg("Amendment would reduce the amount already vested")]
+    AmendmentReducesVestedAmount,
+    #[msg("Grant can only be cancelled before anything has vested")]
+    CancelNotAllowed,
+    #[msg("Grant still has tokens left to vest or claim")]
+    GrantNotSettled,
+    #[msg("Vesting account still has obligations, beneficiary accounts or queued admin actions")]
+    VestingAccountInUse,
+    #[msg("Grant transfer must be approved by the admin")]
+    GrantTransferNeedsApproval,
+    #[msg("Grant must move to a different wallet")]
+    InvalidGrantTransfer,
+    #[msg("Beneficiary has opted out of crank claims")]
+    CrankClaimOptedOut,
+    #[msg("Claim destination cannot be the treasury")]
+    InvalidDestinationTokenAccount,
+    #[msg("Multisig signers changed since this proposal was created")]
+    StaleMultisigProposal,
+    #[msg("Multisig still has open proposals")]
+    MultisigHasOpenProposals,
+    #[msg("Admin action must be queued with the beneficiary account it targets")]
+    InvalidAdminActionTarget,
+    #[msg("Grant has queued admin actions that must be executed or cancelled first")]
+    GrantHasQueuedAdminActions,
+    #[msg("New wallet already holds a grant with this id")]
+    GrantAlreadyExists,
 }
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::state::{AdminAction, ScheduleKind, UnlockCheckpoint};
+
 #[event]
 pub struct VestingAccountCreated {
     pub id: u64,
@@ -13,18 +15,25 @@ pub struct VestingAccountCreated {
 pub struct VestingScheduleInitialized {
     pub beneficiary: Pubkey,
     pub vesting_account: Pubkey,
+    pub grant_id: u64,
     pub start_time: i64,
     pub end_time: i64,
-    pub total_amount: i64,
+    pub total_amount: u64,
     pub cliff_time: i64,
+    pub cliff_amount: u64,
+    pub initial_unlock_amount: u64,
+    pub schedule_kind: ScheduleKind,
+    pub unlock_checkpoints: Vec<UnlockCheckpoint>,
 }
 
 #[event]
 pub struct TokensClaimed {
     pub vesting_account: Pubkey,
     pub beneficiary: Pubkey,
+    pub grant_id: u64,
+    pub destination_token_account: Pubkey,
     pub timestamp: i64,
-    pub amount_claimed: i64,
+    pub amount_claimed: u64,
 }
 
 #[event]
@@ -34,6 +43,20 @@ pub struct TokensTransferedToTreasury {
     pub amount: u64,
 }
 
+#[event]
+pub struct AdminProposed {
+    pub vesting_account: Pubkey,
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+}
+
+#[event]
+pub struct AdminProposalCancelled {
+    pub vesting_account: Pubkey,
+    pub admin: Pubkey,
+    pub cancelled_admin: Pubkey,
+}
+
 #[event]
 pub struct AdminChanged {
     pub vesting_account: Pubkey,
@@ -41,8 +64,200 @@ pub struct AdminChanged {
     pub new_admin: Pubkey,
 }
 
+#[event]
+pub struct RolesUpdated {
+    pub vesting_account: Pubkey,
+    pub admin: Pubkey,
+    pub scheduler: Pubkey,
+    pub revoker: Pubkey,
+    pub treasurer: Pubkey,
+}
+
 #[event]
 pub struct BeneficiaryAccountRevoked {
-    pub vestng_account: Pubkey,
+    pub vesting_account: Pubkey,
+    pub beneficiary: Pubkey,
+    pub grant_id: u64,
+    pub revoked_at: i64,
+    pub vested_unclaimed_amount: u64,
+    pub unvested_amount: u64,
+    pub refund_token_account: Pubkey,
+}
+
+#[event]
+pub struct TreasurySurplusWithdrawn {
+    pub vesting_account: Pubkey,
+    pub treasurer: Pubkey,
+    pub destination_token_account: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct MultisigCreated {
+    pub vesting_account: Pubkey,
+    pub multisig: Pubkey,
+    pub multisig_authority: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct MultisigProposalCreated {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub index: u64,
+    pub proposer: Pubkey,
+}
+
+#[event]
+pub struct MultisigProposalApproved {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub signer: Pubkey,
+    pub approval_count: u32,
+}
+
+#[event]
+pub struct MultisigProposalExecuted {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub executor: Pubkey,
+}
+
+#[event]
+pub struct MultisigProposalCancelled {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub proposer: Pubkey,
+}
+
+#[event]
+pub struct MultisigUpdated {
+    pub multisig: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub config_version: u32,
+}
+
+#[event]
+pub struct AdminActionDelayUpdated {
+    pub vesting_account: Pubkey,
+    pub old_delay: i64,
+    pub new_delay: i64,
+}
+
+#[event]
+pub struct AdminActionQueued {
+    pub vesting_account: Pubkey,
+    pub queued_admin_action: Pubkey,
+    pub action: AdminAction,
+    pub queued_by: Pubkey,
+    pub queued_at: i64,
+}
+
+#[event]
+pub struct AdminActionCancelled {
+    pub vesting_account: Pubkey,
+    pub queued_admin_action: Pubkey,
+    pub action: AdminAction,
+    pub cancelled_by: Pubkey,
+}
+
+#[event]
+pub struct AdminActionExecuted {
+    pub vesting_account: Pubkey,
+    pub queued_admin_action: Pubkey,
+    pub action: AdminAction,
+}
+
+#[event]
+pub struct VestingAccountPaused {
+    pub vesting_account: Pubkey,
+    pub admin: Pubkey,
+}
+
+#[event]
+pub struct VestingAccountUnpaused {
+    pub vesting_account: Pubkey,
+    pub admin: Pubkey,
+}
+
+#[event]
+pub struct BeneficiaryAccountSuspended {
+    pub vesting_account: Pubkey,
+    pub beneficiary: Pubkey,
+    pub grant_id: u64,
+    pub suspended_at: i64,
+}
+
+#[event]
+pub struct BeneficiaryAccountResumed {
+    pub vesting_account: Pubkey,
+    pub beneficiary: Pubkey,
+    pub grant_id: u64,
+    pub resumed_at: i64,
+    pub suspended_seconds: i64,
+    pub end_time: i64,
+}
+
+#[event]
+pub struct VestingScheduleAmended {
+    pub vesting_account: Pubkey,
+    pub beneficiary: Pubkey,
+    pub grant_id: u64,
+    pub old_total_amount: u64,
+    pub new_total_amount: u64,
+    pub old_end_time: i64,
+    pub new_end_time: i64,
+    pub old_cliff_time: i64,
+    pub new_cliff_time: i64,
+}
+
+#[event]
+pub struct VestingScheduleCancelled {
+    pub vesting_account: Pubkey,
+    pub beneficiary: Pubkey,
+    pub grant_id: u64,
+    pub released_amount: u64,
+}
+
+#[event]
+pub struct BeneficiaryAccountClosed {
+    pub vesting_account: Pubkey,
+    pub beneficiary: Pubkey,
+    pub grant_id: u64,
+    pub closed_by: Pubkey,
+    pub rent_payer: Pubkey,
+}
+
+#[event]
+pub struct VestingAccountClosed {
+    pub vesting_account: Pubkey,
+    pub admin: Pubkey,
+    pub swept_amount: u64,
+}
+
+#[event]
+pub struct GrantTransferApprovalUpdated {
+    pub vesting_account: Pubkey,
+    pub admin: Pubkey,
+    pub required: bool,
+}
+
+#[event]
+pub struct GrantTransferred {
+    pub vesting_account: Pubkey,
+    pub grant_id: u64,
+    pub old_beneficiary: Pubkey,
+    pub new_beneficiary: Pubkey,
+    /// Only set when the vesting account requires approval for grant transfers.
+    pub approver: Option<Pubkey>,
+}
+
+#[event]
+pub struct CrankOptOutUpdated {
+    pub vesting_account: Pubkey,
     pub beneficiary: Pubkey,
+    pub grant_id: u64,
+    pub opt_out: bool,
 }
@@ -2,11 +2,12 @@ use anchor_lang::prelude::*;
 
 use crate::{error::CustomError, events::AdminChanged, state::VestingAccount};
 
-pub fn handler(ctx: Context<ChangeAdmin>) -> Result<()> {
+pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
     let vesting_account = &mut ctx.accounts.vesting_account;
-    let old_admin = ctx.accounts.admin.key();
+    let old_admin = vesting_account.admin;
 
     vesting_account.admin = ctx.accounts.new_admin.key();
+    vesting_account.pending_admin = None;
 
     emit!(AdminChanged {
         vesting_account: vesting_account.key(),
@@ -17,13 +18,12 @@ pub fn handler(ctx: Context<ChangeAdmin>) -> Result<()> {
 }
 
 #[derive(Accounts)]
-pub struct ChangeAdmin<'info> {
+pub struct AcceptAdmin<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub new_admin: Signer<'info>,
     #[account(
         mut,
-        constraint = vesting_account.admin == admin.key() @CustomError::UnAuthorized
+        constraint = vesting_account.pending_admin == Some(new_admin.key()) @CustomError::UnAuthorized
     )]
     pub vesting_account: Account<'info, VestingAccount>,
-    pub new_admin: SystemAccount<'info>,
 }
@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::{
+    error::CustomError,
+    events::VestingScheduleAmended,
+    state::{AdminAction, BeneficiaryAccount, QueuedAdminAction, ScheduleKind, VestingAccount},
+    utils::consume_queued_admin_action,
+};
+
+/// Fields left as `None` keep their current value. Lowering the total or pushing out the
+/// cliff or end time has to go through the admin action timelock.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AmendVestingScheduleParams {
+    pub total_amount: Option<u64>,
+    /// Can only be extended.
+    pub end_time: Option<i64>,
+    /// Can only be extended.
+    pub cliff_time: Option<i64>,
+}
+
+pub fn amend_vesting_schedule(
+    ctx: Context<AmendVestingSchedule>,
+    params: AmendVestingScheduleParams,
+) -> Result<()> {
+    let beneficiary_vesting_account = &mut ctx.accounts.beneficiary_vesting_account;
+    require!(
+        beneficiary_vesting_account.revoke_at.is_none(),
+        CustomError::AlreadyRevoked
+    );
+    require!(
+        beneficiary_vesting_account.schedule_kind != ScheduleKind::Piecewise,
+        CustomError::InvalidScheduleAmendment
+    );
+
+    let old_total_amount = beneficiary_vesting_account.total_amount;
+    let old_end_time = beneficiary_vesting_account.end_time;
+    let old_cliff_time = beneficiary_vesting_account.cliff_time;
+    let total_amount = params.total_amount.unwrap_or(old_total_amount);
+    let end_time = params.end_time.unwrap_or(old_end_time);
+    let cliff_time = params.cliff_time.unwrap_or(old_cliff_time);
+
+    require!(
+        end_time >= old_end_time && cliff_time >= old_cliff_time && cliff_time < end_time,
+        CustomError::InvalidScheduleAmendment
+    );
+    require!(total_amount > 0, CustomError::VestingAmountShoulBePositive);
+    require!(
+        beneficiary_vesting_account
+            .initial_unlock_amount
+            .checked_add(beneficiary_vesting_account.cliff_amount)
+            .is_some_and(|unlocked_amount| unlocked_amount <= total_amount),
+        CustomError::InvalidInitialUnlockAmount
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    if total_amount < old_total_amount
+        || end_time > old_end_time
+        || cliff_time > old_cliff_time
+        || ctx.accounts.queued_admin_action.is_some()
+    {
+        consume_queued_admin_action(
+            &mut ctx.accounts.vesting_account,
+            ctx.accounts.queued_admin_action.as_ref(),
+            AdminAction::AmendSchedule {
+                beneficiary_vesting_account: beneficiary_vesting_account.key(),
+                total_amount,
+                end_time,
+                cliff_time,
+            },
+            now,
+            Some(beneficiary_vesting_account),
+        )?;
+    }
+
+    let old_vested_amount = beneficiary_vesting_account.vested_amount(now)?;
+    beneficiary_vesting_account.total_amount = total_amount;
+    beneficiary_vesting_account.end_time = end_time;
+    beneficiary_vesting_account.cliff_time = cliff_time;
+    require!(
+        beneficiary_vesting_account.vested_amount(now)? >= old_vested_amount,
+        CustomError::AmendmentReducesVestedAmount
+    );
+
+    let vesting_account = &mut ctx.accounts.vesting_account;
+    vesting_account.total_token_obligation = vesting_account
+        .total_token_obligation
+        .checked_add(total_amount)
+        .and_then(|obligation| obligation.checked_sub(old_total_amount))
+        .ok_or(CustomError::MathOverflow)?;
+    vesting_account
+        .assert_treasury_covers_obligation(ctx.accounts.treasury_token_account.amount)?;
+
+    emit!(VestingScheduleAmended {
+        vesting_account: vesting_account.key(),
+        beneficiary: ctx.accounts.beneficiary.key(),
+        grant_id: beneficiary_vesting_account.grant_id,
+        old_total_amount,
+        new_total_amount: total_amount,
+        old_end_time,
+        new_end_time: end_time,
+        old_cliff_time,
+        new_cliff_time: cliff_time
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AmendVestingSchedule<'info> {
+    #[account(mut)]
+    pub scheduler: Signer<'info>,
+    pub beneficiary: SystemAccount<'info>,
+    #[account(
+        mut,
+        constraint = vesting_account.scheduler == scheduler.key() @CustomError::UnAuthorized,
+        has_one = treasury_token_account
+    )]
+    pub vesting_account: Account<'info, VestingAccount>,
+    #[account(
+        seeds = [b"vesting_treasury", vesting_account.key().as_ref()],
+        bump = vesting_account.treasury_bump
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"beneficiary_vesting_account", beneficiary.key().as_ref(), vesting_account.key().as_ref(), &beneficiary_vesting_account.grant_id.to_le_bytes()],
+        bump = beneficiary_vesting_account.bump,
+        has_one = beneficiary,
+        has_one = vesting_account
+    )]
+    pub beneficiary_vesting_account: Account<'info, BeneficiaryAccount>,
+    #[account(
+        mut,
+        close = scheduler,
+        seeds = [b"queued_admin_action", vesting_account.key().as_ref(), &queued_admin_action.index.to_le_bytes()],
+        bump = queued_admin_action.bump,
+        has_one = vesting_account
+    )]
+    pub queued_admin_action: Option<Account<'info, QueuedAdminAction>>,
+}
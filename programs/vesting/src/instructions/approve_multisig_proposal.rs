@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::CustomError,
+    events::MultisigProposalApproved,
+    state::{Multisig, MultisigProposal},
+};
+
+pub fn approve_multisig_proposal(ctx: Context<ApproveMultisigProposal>) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    require!(!proposal.executed, CustomError::ProposalAlreadyExecuted);
+    require!(
+        proposal.config_version == ctx.accounts.multisig.config_version,
+        CustomError::StaleMultisigProposal
+    );
+
+    let signer_index = ctx
+        .accounts
+        .multisig
+        .signer_index(&ctx.accounts.signer.key())
+        .ok_or(CustomError::NotAMultisigSigner)?;
+    require!(
+        !proposal.is_approved_by(signer_index),
+        CustomError::ProposalAlreadyApproved
+    );
+    proposal.approve(signer_index);
+
+    emit!(MultisigProposalApproved {
+        multisig: ctx.accounts.multisig.key(),
+        proposal: proposal.key(),
+        signer: ctx.accounts.signer.key(),
+        approval_count: proposal.approval_count()
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ApproveMultisigProposal<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        seeds = [b"multisig", multisig.vesting_account.as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+    #[account(
+        mut,
+        seeds = [b"multisig_proposal", multisig.key().as_ref(), &proposal.index.to_le_bytes()],
+        bump = proposal.bump,
+        has_one = multisig
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+}
@@ -0,0 +1,222 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::{
+        create_idempotent, get_associated_token_address_with_program_id, AssociatedToken, Create,
+    },
+    token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::{
+    events::TokensClaimed,
+    state::{BeneficiaryAccount, VestingAccount},
+    utils::transfer_from_treasury,
+    CustomError,
+};
+
+struct TreasuryPayout<'info> {
+    vesting_account: &'info AccountInfo<'info>,
+    treasury_token_account: &'info AccountInfo<'info>,
+    mint: &'info AccountInfo<'info>,
+    destination_token_account: &'info AccountInfo<'info>,
+    treasury_bump: u8,
+    decimals: u8,
+    amount: u64,
+}
+
+/// Claims every schedule passed in `remaining_accounts` as
+/// (vesting account, treasury, beneficiary account, mint, beneficiary ATA) groups,
+/// paying each treasury out once into the beneficiary's ATA for its mint.
+pub fn batch_claim_vested_tokens<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BatchClaimTokens<'info>>,
+) -> Result<()> {
+    let schedules = ctx.remaining_accounts.chunks_exact(5);
+    require!(
+        !ctx.remaining_accounts.is_empty() && schedules.remainder().is_empty(),
+        CustomError::InvalidBatchClaimAccounts
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let beneficiary = ctx.accounts.beneficiary.key();
+    let token_program = ctx.accounts.token_program.key();
+    let mut payouts: Vec<TreasuryPayout<'info>> = Vec::new();
+
+    for schedule_accounts in schedules {
+        let vesting_account_info = &schedule_accounts[0];
+        let treasury_token_account_info = &schedule_accounts[1];
+        let beneficiary_vesting_account_info = &schedule_accounts[2];
+        let mint_info = &schedule_accounts[3];
+        let destination_token_account_info = &schedule_accounts[4];
+        require!(
+            vesting_account_info.is_writable
+                && beneficiary_vesting_account_info.is_writable
+                && destination_token_account_info.is_writable,
+            CustomError::InvalidBatchClaimAccounts
+        );
+
+        let mut vesting_account = Account::<VestingAccount>::try_from(vesting_account_info)?;
+        require_keys_eq!(
+            vesting_account.key(),
+            Pubkey::create_program_address(
+                &[
+                    b"vesting_account",
+                    vesting_account.company_name.as_bytes(),
+                    &vesting_account.id.to_le_bytes(),
+                    &[vesting_account.bump],
+                ],
+                ctx.program_id,
+            )
+            .map_err(|_| CustomError::InvalidBatchClaimAccounts)?,
+            CustomError::InvalidBatchClaimAccounts
+        );
+        require_keys_eq!(
+            vesting_account.mint,
+            mint_info.key(),
+            CustomError::InvalidMint
+        );
+        require!(!vesting_account.paused, CustomError::VestingAccountIsPaused);
+        require_keys_eq!(
+            vesting_account.treasury_token_account,
+            treasury_token_account_info.key(),
+            CustomError::InvalidBatchClaimAccounts
+        );
+
+        let mut beneficiary_vesting_account =
+            Account::<BeneficiaryAccount>::try_from(beneficiary_vesting_account_info)?;
+        require_keys_eq!(
+            beneficiary_vesting_account.beneficiary,
+            beneficiary,
+            CustomError::InvalidBatchClaimAccounts
+        );
+        require_keys_eq!(
+            beneficiary_vesting_account.vesting_account,
+            vesting_account.key(),
+            CustomError::InvalidBatchClaimAccounts
+        );
+        require_keys_eq!(
+            beneficiary_vesting_account.key(),
+            Pubkey::create_program_address(
+                &[
+                    b"beneficiary_vesting_account",
+                    beneficiary.as_ref(),
+                    vesting_account.key().as_ref(),
+                    &beneficiary_vesting_account.grant_id.to_le_bytes(),
+                    &[beneficiary_vesting_account.bump],
+                ],
+                ctx.program_id,
+            )
+            .map_err(|_| CustomError::InvalidBatchClaimAccounts)?,
+            CustomError::InvalidBatchClaimAccounts
+        );
+
+        let claimable_amount = beneficiary_vesting_account.claimable_amount(now)?;
+        if claimable_amount == 0 {
+            continue;
+        }
+
+        beneficiary_vesting_account.record_withdrawal(claimable_amount)?;
+        vesting_account.release_obligation(claimable_amount)?;
+        beneficiary_vesting_account.exit(ctx.program_id)?;
+        vesting_account.exit(ctx.program_id)?;
+
+        match payouts
+            .iter_mut()
+            .find(|payout| payout.vesting_account.key() == vesting_account.key())
+        {
+            Some(payout) => {
+                require_keys_eq!(
+                    payout.destination_token_account.key(),
+                    destination_token_account_info.key(),
+                    CustomError::InvalidBatchClaimAccounts
+                );
+                payout.amount = payout
+                    .amount
+                    .checked_add(claimable_amount)
+                    .ok_or(CustomError::MathOverflow)?;
+            }
+            None => {
+                require_keys_eq!(
+                    *mint_info.owner,
+                    token_program,
+                    CustomError::InvalidBatchClaimAccounts
+                );
+                require_keys_eq!(
+                    destination_token_account_info.key(),
+                    get_associated_token_address_with_program_id(
+                        &beneficiary,
+                        &mint_info.key(),
+                        &token_program
+                    ),
+                    CustomError::InvalidBatchClaimAccounts
+                );
+                payouts.push(TreasuryPayout {
+                    vesting_account: vesting_account_info,
+                    treasury_token_account: treasury_token_account_info,
+                    mint: mint_info,
+                    destination_token_account: destination_token_account_info,
+                    treasury_bump: vesting_account.treasury_bump,
+                    decimals: InterfaceAccount::<Mint>::try_from(mint_info)?.decimals,
+                    amount: claimable_amount,
+                });
+            }
+        }
+
+        emit!(TokensClaimed {
+            beneficiary,
+            vesting_account: vesting_account.key(),
+            grant_id: beneficiary_vesting_account.grant_id,
+            destination_token_account: destination_token_account_info.key(),
+            timestamp: now,
+            amount_claimed: claimable_amount
+        });
+    }
+
+    require!(!payouts.is_empty(), CustomError::NothingToClaim);
+
+    for payout in payouts {
+        if payout.destination_token_account.data_is_empty() {
+            create_idempotent(CpiContext::new(
+                ctx.accounts.associated_token_program.to_account_info(),
+                Create {
+                    payer: ctx.accounts.beneficiary.to_account_info(),
+                    associated_token: payout.destination_token_account.clone(),
+                    authority: ctx.accounts.beneficiary.to_account_info(),
+                    mint: payout.mint.clone(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    token_program: ctx.accounts.token_program.to_account_info(),
+                },
+            ))?;
+        }
+
+        let transfer_token_cpi_account = TransferChecked {
+            mint: payout.mint.clone(),
+            from: payout.treasury_token_account.clone(),
+            to: payout.destination_token_account.clone(),
+            authority: payout.treasury_token_account.clone(),
+        };
+
+        transfer_from_treasury(
+            ctx.accounts.token_program.to_account_info(),
+            transfer_token_cpi_account,
+            payout.vesting_account.key(),
+            payout.treasury_bump,
+            payout.amount,
+            payout.decimals,
+        )?;
+
+        let vesting_account = Account::<VestingAccount>::try_from(payout.vesting_account)?;
+        let treasury_token_account =
+            InterfaceAccount::<TokenAccount>::try_from(payout.treasury_token_account)?;
+        vesting_account.assert_treasury_covers_obligation(treasury_token_account.amount)?;
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct BatchClaimTokens<'info> {
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
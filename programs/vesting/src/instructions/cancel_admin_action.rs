@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::CustomError,
+    events::AdminActionCancelled,
+    state::{BeneficiaryAccount, QueuedAdminAction, VestingAccount},
+};
+
+pub fn cancel_admin_action(ctx: Context<CancelAdminAction>) -> Result<()> {
+    let vesting_account = &mut ctx.accounts.vesting_account;
+    let queued_admin_action = &ctx.accounts.queued_admin_action;
+    let authority = ctx.accounts.authority.key();
+    require!(
+        authority == vesting_account.admin
+            || authority == queued_admin_action.action.authority(vesting_account),
+        CustomError::UnAuthorized
+    );
+    vesting_account.open_admin_action_count = vesting_account
+        .open_admin_action_count
+        .checked_sub(1)
+        .ok_or(CustomError::MathOverflow)?;
+    if let Some(target) = queued_admin_action.action.beneficiary_vesting_account() {
+        let beneficiary_vesting_account = ctx
+            .accounts
+            .beneficiary_vesting_account
+            .as_mut()
+            .filter(|beneficiary_vesting_account| beneficiary_vesting_account.key() == target)
+            .ok_or(CustomError::InvalidAdminActionTarget)?;
+        beneficiary_vesting_account.remove_admin_action()?;
+    }
+
+    emit!(AdminActionCancelled {
+        vesting_account: vesting_account.key(),
+        queued_admin_action: queued_admin_action.key(),
+        action: queued_admin_action.action.clone(),
+        cancelled_by: authority
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelAdminAction<'info> {
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub vesting_account: Account<'info, VestingAccount>,
+    /// CHECK: Receives the rent of the cancelled action.
+    #[account(mut)]
+    pub queued_by: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = queued_by,
+        seeds = [b"queued_admin_action", vesting_account.key().as_ref(), &queued_admin_action.index.to_le_bytes()],
+        bump = queued_admin_action.bump,
+        has_one = vesting_account,
+        has_one = queued_by
+    )]
+    pub queued_admin_action: Account<'info, QueuedAdminAction>,
+    /// Required when the action targets a grant.
+    #[account(
+        mut,
+        has_one = vesting_account
+    )]
+    pub beneficiary_vesting_account: Option<Account<'info, BeneficiaryAccount>>,
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::CustomError, events::AdminProposalCancelled, state::VestingAccount};
+
+pub fn cancel_admin_proposal(ctx: Context<CancelAdminProposal>) -> Result<()> {
+    let vesting_account = &mut ctx.accounts.vesting_account;
+    let cancelled_admin = vesting_account
+        .pending_admin
+        .take()
+        .ok_or(CustomError::NoPendingAdmin)?;
+
+    emit!(AdminProposalCancelled {
+        vesting_account: vesting_account.key(),
+        admin: ctx.accounts.admin.key(),
+        cancelled_admin
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelAdminProposal<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        constraint = vesting_account.admin == admin.key() @CustomError::UnAuthorized
+    )]
+    pub vesting_account: Account<'info, VestingAccount>,
+}
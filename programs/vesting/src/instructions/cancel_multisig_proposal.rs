@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::CustomError,
+    events::MultisigProposalCancelled,
+    state::{Multisig, MultisigProposal},
+};
+
+/// Withdraws a proposal that was never executed, refunding its rent to the proposer.
+pub fn cancel_multisig_proposal(ctx: Context<CancelMultisigProposal>) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+    multisig.open_proposal_count = multisig
+        .open_proposal_count
+        .checked_sub(1)
+        .ok_or(CustomError::MathOverflow)?;
+
+    emit!(MultisigProposalCancelled {
+        multisig: multisig.key(),
+        proposal: ctx.accounts.proposal.key(),
+        proposer: ctx.accounts.proposer.key()
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelMultisigProposal<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"multisig", multisig.vesting_account.as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [b"multisig_proposal", multisig.key().as_ref(), &proposal.index.to_le_bytes()],
+        bump = proposal.bump,
+        has_one = multisig,
+        has_one = proposer
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::CustomError,
+    events::VestingScheduleCancelled,
+    state::{AdminAction, BeneficiaryAccount, QueuedAdminAction, VestingAccount},
+    utils::consume_queued_admin_action,
+};
+
+/// Rescinds a grant before anything has vested, releasing its whole obligation and
+/// returning the account's rent to the admin.
+pub fn cancel_vesting_schedule(ctx: Context<CancelVestingSchedule>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    consume_queued_admin_action(
+        &mut ctx.accounts.vesting_account,
+        ctx.accounts.queued_admin_action.as_ref(),
+        AdminAction::Cancel {
+            beneficiary_vesting_account: ctx.accounts.beneficiary_vesting_account.key(),
+        },
+        now,
+        Some(&mut ctx.accounts.beneficiary_vesting_account),
+    )?;
+
+    let beneficiary_vesting_account = &ctx.accounts.beneficiary_vesting_account;
+    require!(
+        beneficiary_vesting_account.revoke_at.is_none(),
+        CustomError::AlreadyRevoked
+    );
+    require!(
+        beneficiary_vesting_account.open_admin_action_count == 0,
+        CustomError::GrantHasQueuedAdminActions
+    );
+
+    require!(
+        now < beneficiary_vesting_account.start_time
+            || (now < beneficiary_vesting_account.cliff_time
+                && beneficiary_vesting_account.vested_amount(now)? == 0
+                && beneficiary_vesting_account.total_withdrawn == 0),
+        CustomError::CancelNotAllowed
+    );
+
+    let released_amount = beneficiary_vesting_account.total_amount;
+    let vesting_account = &mut ctx.accounts.vesting_account;
+    vesting_account.release_obligation(released_amount)?;
+    vesting_account.remove_beneficiary_account()?;
+
+    emit!(VestingScheduleCancelled {
+        vesting_account: ctx.accounts.vesting_account.key(),
+        beneficiary: ctx.accounts.beneficiary.key(),
+        grant_id: beneficiary_vesting_account.grant_id,
+        released_amount
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelVestingSchedule<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub beneficiary: SystemAccount<'info>,
+    #[account(
+        mut,
+        constraint = vesting_account.admin == admin.key() @CustomError::UnAuthorized
+    )]
+    pub vesting_account: Account<'info, VestingAccount>,
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"beneficiary_vesting_account", beneficiary.key().as_ref(), vesting_account.key().as_ref(), &beneficiary_vesting_account.grant_id.to_le_bytes()],
+        bump = beneficiary_vesting_account.bump,
+        has_one = beneficiary,
+        has_one = vesting_account
+    )]
+    pub beneficiary_vesting_account: Account<'info, BeneficiaryAccount>,
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"queued_admin_action", vesting_account.key().as_ref(), &queued_admin_action.index.to_le_bytes()],
+        bump = queued_admin_action.bump,
+        has_one = vesting_account
+    )]
+    pub queued_admin_action: Option<Account<'info, QueuedAdminAction>>,
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    state::{BeneficiaryAccount, VestingAccount},
+    utils::claim_vested_tokens_into,
+    CustomError,
+};
+
+/// Same as `claim_vested_tokens`, paying into any token account of the vesting mint.
+pub fn claim_vested_tokens_to(ctx: Context<ClaimTokensTo>) -> Result<()> {
+    claim_vested_tokens_into(
+        &mut ctx.accounts.vesting_account,
+        &mut ctx.accounts.beneficiary_vesting_account,
+        &ctx.accounts.mint,
+        &mut ctx.accounts.treasury_token_account,
+        ctx.accounts.destination_token_account.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+    )
+}
+
+#[derive(Accounts)]
+pub struct ClaimTokensTo<'info> {
+    pub beneficiary: Signer<'info>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+        constraint = destination_token_account.key() != treasury_token_account.key() @CustomError::InvalidDestinationTokenAccount
+    )]
+    pub destination_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"vesting_account", vesting_account.company_name.as_bytes(), &vesting_account.id.to_le_bytes()],
+        bump = vesting_account.bump,
+        constraint = !vesting_account.paused @CustomError::VestingAccountIsPaused,
+        has_one = treasury_token_account,
+        has_one = mint
+    )]
+    pub vesting_account: Account<'info, VestingAccount>,
+    #[account(
+        mut,
+        seeds = [b"vesting_treasury", vesting_account.key().as_ref()],
+        bump = vesting_account.treasury_bump
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"beneficiary_vesting_account", beneficiary.key().as_ref(), vesting_account.key().as_ref(), &beneficiary_vesting_account.grant_id.to_le_bytes()],
+        bump = beneficiary_vesting_account.bump,
+        has_one = beneficiary,
+        has_one = vesting_account
+    )]
+    pub beneficiary_vesting_account: Account<'info, BeneficiaryAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::CustomError,
+    events::BeneficiaryAccountClosed,
+    state::{BeneficiaryAccount, VestingAccount},
+};
+
+pub fn close_beneficiary_account(ctx: Context<CloseBeneficiaryAccount>) -> Result<()> {
+    let beneficiary_vesting_account = &ctx.accounts.beneficiary_vesting_account;
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        beneficiary_vesting_account.is_settled(now)?,
+        CustomError::GrantNotSettled
+    );
+    require!(
+        beneficiary_vesting_account.open_admin_action_count == 0,
+        CustomError::GrantHasQueuedAdminActions
+    );
+    ctx.accounts.vesting_account.remove_beneficiary_account()?;
+
+    emit!(BeneficiaryAccountClosed {
+        vesting_account: ctx.accounts.vesting_account.key(),
+        beneficiary: ctx.accounts.beneficiary.key(),
+        grant_id: beneficiary_vesting_account.grant_id,
+        closed_by: ctx.accounts.authority.key(),
+        rent_payer: ctx.accounts.rent_payer.key()
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseBeneficiaryAccount<'info> {
+    #[account(
+        constraint = authority.key() == beneficiary.key() || authority.key() == vesting_account.admin @CustomError::UnAuthorized
+    )]
+    pub authority: Signer<'info>,
+    pub beneficiary: SystemAccount<'info>,
+    #[account(mut)]
+    pub vesting_account: Account<'info, VestingAccount>,
+    /// CHECK: Paid for the beneficiary account and gets its rent back.
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [b"beneficiary_vesting_account", beneficiary.key().as_ref(), vesting_account.key().as_ref(), &beneficiary_vesting_account.grant_id.to_le_bytes()],
+        bump = beneficiary_vesting_account.bump,
+        has_one = beneficiary,
+        has_one = vesting_account,
+        has_one = rent_payer
+    )]
+    pub beneficiary_vesting_account: Account<'info, BeneficiaryAccount>,
+}
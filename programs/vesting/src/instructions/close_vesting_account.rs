@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::{
+    error::CustomError,
+    events::VestingAccountClosed,
+    state::{Multisig, VestingAccount},
+    utils::{close_program_account, close_treasury, transfer_from_treasury},
+};
+
+/// Retires a wound-down vesting account, sweeping leftover treasury tokens to the admin.
+/// Its multisig, if one was created, is closed along with it so the addresses can be reused.
+pub fn close_vesting_account(ctx: Context<CloseVestingAccount>) -> Result<()> {
+    let vesting_account = &ctx.accounts.vesting_account;
+    require!(
+        vesting_account.total_token_obligation == 0
+            && vesting_account.beneficiary_count == 0
+            && vesting_account.open_admin_action_count == 0,
+        CustomError::VestingAccountInUse
+    );
+
+    let multisig_info = ctx.accounts.multisig.to_account_info();
+    if multisig_info.owner == ctx.program_id {
+        let multisig = Multisig::try_deserialize(&mut &multisig_info.try_borrow_data()?[..])?;
+        require!(
+            multisig.open_proposal_count == 0,
+            CustomError::MultisigHasOpenProposals
+        );
+        close_program_account(&multisig_info, &ctx.accounts.admin.to_account_info())?;
+    }
+
+    let vesting_account_key = vesting_account.key();
+    let swept_amount = ctx.accounts.treasury_token_account.amount;
+    if swept_amount > 0 {
+        let transfer_token_cpi_account = TransferChecked {
+            mint: ctx.accounts.mint.to_account_info(),
+            from: ctx.accounts.treasury_token_account.to_account_info(),
+            to: ctx.accounts.admin_token_account.to_account_info(),
+            authority: ctx.accounts.treasury_token_account.to_account_info(),
+        };
+        transfer_from_treasury(
+            ctx.accounts.token_program.to_account_info(),
+            transfer_token_cpi_account,
+            vesting_account_key,
+            vesting_account.treasury_bump,
+            swept_amount,
+            ctx.accounts.mint.decimals,
+        )?;
+    }
+
+    let close_treasury_cpi_account = CloseAccount {
+        account: ctx.accounts.treasury_token_account.to_account_info(),
+        destination: ctx.accounts.admin.to_account_info(),
+        authority: ctx.accounts.treasury_token_account.to_account_info(),
+    };
+    close_treasury(
+        ctx.accounts.token_program.to_account_info(),
+        close_treasury_cpi_account,
+        vesting_account_key,
+        vesting_account.treasury_bump,
+    )?;
+
+    emit!(VestingAccountClosed {
+        vesting_account: vesting_account_key,
+        admin: ctx.accounts.admin.key(),
+        swept_amount
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseVestingAccount<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        close = admin,
+        constraint = vesting_account.admin == admin.key() @CustomError::UnAuthorized,
+        constraint = !vesting_account.paused @CustomError::VestingAccountIsPaused,
+        has_one = treasury_token_account,
+        has_one = mint
+    )]
+    pub vesting_account: Account<'info, VestingAccount>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [b"vesting_treasury", vesting_account.key().as_ref()],
+        bump = vesting_account.treasury_bump
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = admin,
+        token::token_program = token_program
+    )]
+    pub admin_token_account: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK: The vesting account's multisig PDA, which may not exist.
+    #[account(
+        mut,
+        seeds = [b"multisig", vesting_account.key().as_ref()],
+        bump
+    )]
+    pub multisig: UncheckedAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    state::{BeneficiaryAccount, VestingAccount},
+    utils::claim_vested_tokens_into,
+    CustomError,
+};
+
+/// Claims on a beneficiary's behalf into their canonical ATA; the cranker pays fees and rent.
+pub fn crank_claim_vested_tokens(ctx: Context<CrankClaimTokens>) -> Result<()> {
+    claim_vested_tokens_into(
+        &mut ctx.accounts.vesting_account,
+        &mut ctx.accounts.beneficiary_vesting_account,
+        &ctx.accounts.mint,
+        &mut ctx.accounts.treasury_token_account,
+        ctx.accounts.beneficiary_ata.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+    )
+}
+
+#[derive(Accounts)]
+pub struct CrankClaimTokens<'info> {
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+    pub beneficiary: SystemAccount<'info>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        associated_token::mint = mint,
+        associated_token::authority = beneficiary,
+        associated_token::token_program = token_program
+    )]
+    pub beneficiary_ata: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"vesting_account", vesting_account.company_name.as_bytes(), &vesting_account.id.to_le_bytes()],
+        bump = vesting_account.bump,
+        constraint = !vesting_account.paused @CustomError::VestingAccountIsPaused,
+        has_one = treasury_token_account,
+        has_one = mint
+    )]
+    pub vesting_account: Account<'info, VestingAccount>,
+    #[account(
+        mut,
+        seeds = [b"vesting_treasury", vesting_account.key().as_ref()],
+        bump = vesting_account.treasury_bump
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"beneficiary_vesting_account", beneficiary.key().as_ref(), vesting_account.key().as_ref(), &beneficiary_vesting_account.grant_id.to_le_bytes()],
+        bump = beneficiary_vesting_account.bump,
+        constraint = !beneficiary_vesting_account.crank_opt_out @CustomError::CrankClaimOptedOut,
+        has_one = beneficiary,
+        has_one = vesting_account
+    )]
+    pub beneficiary_vesting_account: Account<'info, BeneficiaryAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
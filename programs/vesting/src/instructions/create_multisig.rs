@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constant::ANCHOR_DISCRIMINATOR_SIZE,
+    error::CustomError,
+    events::MultisigCreated,
+    state::{Multisig, VestingAccount},
+};
+
+pub fn create_multisig(
+    ctx: Context<CreateMultisig>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    Multisig::validate_config(&signers, threshold)?;
+
+    ctx.accounts.multisig.set_inner(Multisig {
+        vesting_account: ctx.accounts.vesting_account.key(),
+        signers: signers.clone(),
+        threshold,
+        proposal_count: 0,
+        open_proposal_count: 0,
+        config_version: 0,
+        authority_bump: ctx.bumps.multisig_authority,
+        bump: ctx.bumps.multisig,
+    });
+
+    emit!(MultisigCreated {
+        vesting_account: ctx.accounts.vesting_account.key(),
+        multisig: ctx.accounts.multisig.key(),
+        multisig_authority: ctx.accounts.multisig_authority.key(),
+        signers,
+        threshold
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateMultisig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        constraint = vesting_account.admin == admin.key() @CustomError::UnAuthorized
+    )]
+    pub vesting_account: Account<'info, VestingAccount>,
+    #[account(
+        init,
+        payer = admin,
+        space = ANCHOR_DISCRIMINATOR_SIZE + Multisig::INIT_SPACE,
+        seeds = [b"multisig", vesting_account.key().as_ref()],
+        bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+    /// CHECK: Data-less PDA that signs for the multisig; hand it admin or role keys.
+    #[account(
+        seeds = [b"multisig_authority", multisig.key().as_ref()],
+        bump
+    )]
+    pub multisig_authority: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
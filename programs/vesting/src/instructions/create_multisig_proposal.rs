@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constant::{MAX_PROPOSAL_ACCOUNTS, MAX_PROPOSAL_DATA_LEN},
+    error::CustomError,
+    events::MultisigProposalCreated,
+    state::{Multisig, MultisigProposal, ProposalAccountMeta},
+};
+
+pub fn create_multisig_proposal(
+    ctx: Context<CreateMultisigProposal>,
+    instruction_accounts: Vec<ProposalAccountMeta>,
+    instruction_data: Vec<u8>,
+) -> Result<()> {
+    require!(
+        instruction_accounts.len() <= MAX_PROPOSAL_ACCOUNTS
+            && instruction_data.len() <= MAX_PROPOSAL_DATA_LEN,
+        CustomError::InvalidProposalInstruction
+    );
+
+    let multisig = &mut ctx.accounts.multisig;
+    let signer_index = multisig
+        .signer_index(&ctx.accounts.proposer.key())
+        .ok_or(CustomError::NotAMultisigSigner)?;
+    let index = multisig.proposal_count;
+    multisig.proposal_count = index.checked_add(1).ok_or(CustomError::MathOverflow)?;
+    multisig.open_proposal_count = multisig
+        .open_proposal_count
+        .checked_add(1)
+        .ok_or(CustomError::MathOverflow)?;
+
+    let mut proposal = MultisigProposal {
+        multisig: multisig.key(),
+        index,
+        proposer: ctx.accounts.proposer.key(),
+        config_version: multisig.config_version,
+        approvals: 0,
+        executed: false,
+        bump: ctx.bumps.proposal,
+        instruction_accounts,
+        instruction_data,
+    };
+    proposal.approve(signer_index);
+    ctx.accounts.proposal.set_inner(proposal);
+
+    emit!(MultisigProposalCreated {
+        multisig: ctx.accounts.multisig.key(),
+        proposal: ctx.accounts.proposal.key(),
+        index,
+        proposer: ctx.accounts.proposer.key()
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(instruction_accounts: Vec<ProposalAccountMeta>, instruction_data: Vec<u8>)]
+pub struct CreateMultisigProposal<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"multisig", multisig.vesting_account.as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+    #[account(
+        init,
+        payer = proposer,
+        space = MultisigProposal::space(instruction_accounts.len(), instruction_data.len()),
+        seeds = [b"multisig_proposal", multisig.key().as_ref(), &multisig.proposal_count.to_le_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+    pub system_program: Program<'info, System>,
+}
@@ -5,16 +5,30 @@ use crate::{
     events::VestingAccountCreated, CustomError, VestingAccount, ANCHOR_DISCRIMINATOR_SIZE,
 };
 
-pub fn handler(ctx: Context<CreateVestingAccount>, id: u64, company_name: String) -> Result<()> {
+pub fn create_vesting_account(
+    ctx: Context<CreateVestingAccount>,
+    id: u64,
+    company_name: String,
+) -> Result<()> {
     require!(company_name.len() <= 50, CustomError::CompanyNameTooLong);
 
     ctx.accounts.vesting_account.set_inner(VestingAccount {
         id,
         admin: ctx.accounts.admin.key(),
+        pending_admin: None,
+        scheduler: ctx.accounts.admin.key(),
+        revoker: ctx.accounts.admin.key(),
+        treasurer: ctx.accounts.admin.key(),
         mint: ctx.accounts.mint.key(),
         treasury_token_account: ctx.accounts.treasury_token_account.key(),
         company_name: company_name.clone(),
         total_token_obligation: 0,
+        beneficiary_count: 0,
+        paused: false,
+        grant_transfer_requires_approval: false,
+        admin_action_delay: 0,
+        queued_admin_action_count: 0,
+        open_admin_action_count: 0,
         treasury_bump: ctx.bumps.treasury_token_account,
         bump: ctx.bumps.vesting_account,
     });
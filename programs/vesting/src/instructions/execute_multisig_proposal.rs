@@ -0,0 +1,106 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        program::invoke_signed,
+    },
+};
+
+use crate::{
+    error::CustomError,
+    events::MultisigProposalExecuted,
+    state::{Multisig, MultisigProposal},
+};
+
+/// Runs the proposed instruction against this program with the multisig authority
+/// signing, then closes the proposal back to its proposer. `remaining_accounts` must
+/// hold every account the instruction references, plus this program.
+pub fn execute_multisig_proposal<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExecuteMultisigProposal<'info>>,
+) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+    require!(
+        multisig
+            .signer_index(&ctx.accounts.executor.key())
+            .is_some(),
+        CustomError::NotAMultisigSigner
+    );
+
+    let proposal = &mut ctx.accounts.proposal;
+    require!(!proposal.executed, CustomError::ProposalAlreadyExecuted);
+    require!(
+        proposal.config_version == multisig.config_version,
+        CustomError::StaleMultisigProposal
+    );
+    require!(
+        proposal.approval_count() >= u32::from(multisig.threshold),
+        CustomError::NotEnoughApprovals
+    );
+
+    // Persist the executed flag before the CPI so the proposal cannot be replayed
+    // from inside the instruction it runs.
+    proposal.executed = true;
+    proposal.exit(ctx.program_id)?;
+    multisig.open_proposal_count = multisig
+        .open_proposal_count
+        .checked_sub(1)
+        .ok_or(CustomError::MathOverflow)?;
+    multisig.exit(ctx.program_id)?;
+
+    let instruction = Instruction {
+        program_id: *ctx.program_id,
+        accounts: proposal
+            .instruction_accounts
+            .iter()
+            .map(|meta| AccountMeta {
+                pubkey: meta.pubkey,
+                is_signer: meta.is_signer,
+                is_writable: meta.is_writable,
+            })
+            .collect(),
+        data: proposal.instruction_data.clone(),
+    };
+
+    let multisig_key = multisig.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"multisig_authority",
+        multisig_key.as_ref(),
+        &[multisig.authority_bump],
+    ]];
+    invoke_signed(&instruction, ctx.remaining_accounts, signer_seeds)?;
+    // The proposal may have updated or closed the multisig; pick up what it left so
+    // exit does not write the stale copy back.
+    if !multisig.to_account_info().data_is_empty() {
+        multisig.reload()?;
+    }
+
+    emit!(MultisigProposalExecuted {
+        multisig: multisig_key,
+        proposal: proposal.key(),
+        executor: ctx.accounts.executor.key()
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExecuteMultisigProposal<'info> {
+    pub executor: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"multisig", multisig.vesting_account.as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [b"multisig_proposal", multisig.key().as_ref(), &proposal.index.to_le_bytes()],
+        bump = proposal.bump,
+        has_one = multisig,
+        has_one = proposer
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+    /// CHECK: Paid for the proposal and gets its rent back.
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+}
@@ -2,66 +2,172 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount};
 
 use crate::{
-    constant::ANCHOR_DISCRIMINATOR_SIZE,
+    constant::MAX_UNLOCK_CHECKPOINTS,
     events::VestingScheduleInitialized,
-    state::{BeneficiaryAccount, VestingAccount},
+    state::{BeneficiaryAccount, ScheduleKind, UnlockCheckpoint, VestingAccount},
     CustomError,
 };
 
-pub fn handler(
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct VestingScheduleParams {
+    pub start_time: i64,
+    pub end_time: i64,
+    pub total_amount: u64,
+    pub cliff_time: i64,
+    /// Lump sum released at `cliff_time`.
+    pub cliff_amount: u64,
+    /// Portion claimable from `start_time`, before the cliff.
+    pub initial_unlock_amount: u64,
+    pub schedule_kind: ScheduleKind,
+    /// Ordered unlock table, only used by piecewise schedules.
+    pub unlock_checkpoints: Vec<UnlockCheckpoint>,
+}
+
+pub fn initialize_vesting_schedule(
     ctx: Context<InitializeVestingSchedule>,
-    start_time: i64,
-    end_time: i64,
-    total_amount: i64,
-    cliff_time: i64,
+    grant_id: u64,
+    params: VestingScheduleParams,
 ) -> Result<()> {
+    let VestingScheduleParams {
+        start_time,
+        end_time,
+        total_amount,
+        cliff_time,
+        cliff_amount,
+        initial_unlock_amount,
+        schedule_kind,
+        unlock_checkpoints,
+    } = params;
+
     require!(
-        start_time < end_time && start_time < cliff_time && cliff_time < end_time,
+        start_time < end_time && start_time <= cliff_time && cliff_time < end_time,
         CustomError::InvalidVestingSchedule
     );
     require!(total_amount > 0, CustomError::VestingAmountShoulBePositive);
+    require!(
+        cliff_amount <= total_amount,
+        CustomError::InvalidCliffAmount
+    );
+    require!(
+        initial_unlock_amount
+            .checked_add(cliff_amount)
+            .is_some_and(|unlocked_amount| unlocked_amount <= total_amount),
+        CustomError::InvalidInitialUnlockAmount
+    );
+    if let ScheduleKind::Periodic { period_seconds } = schedule_kind {
+        require!(
+            period_seconds > 0 && period_seconds <= end_time.saturating_sub(start_time),
+            CustomError::InvalidVestingPeriod
+        );
+    }
+    if schedule_kind == ScheduleKind::Piecewise {
+        require!(
+            cliff_amount == 0 && initial_unlock_amount == 0,
+            CustomError::InvalidUnlockCheckpoints
+        );
+        validate_unlock_checkpoints(&unlock_checkpoints, start_time, end_time, total_amount)?;
+    } else {
+        require!(
+            unlock_checkpoints.is_empty(),
+            CustomError::InvalidUnlockCheckpoints
+        );
+    }
 
     ctx.accounts
         .beneficiary_vesting_account
         .set_inner(BeneficiaryAccount {
             beneficiary: ctx.accounts.beneficiary.key(),
             vesting_account: ctx.accounts.vesting_account.key(),
+            grant_id,
             start_time,
             end_time,
             total_amount,
             total_withdrawn: 0,
             cliff_time,
+            cliff_amount,
+            initial_unlock_amount,
             revoke_at: None,
+            suspended_at: None,
+            suspended_seconds: 0,
+            schedule_kind,
+            rent_payer: ctx.accounts.scheduler.key(),
+            crank_opt_out: false,
+            open_admin_action_count: 0,
             bump: ctx.bumps.beneficiary_vesting_account,
+            unlock_checkpoints: unlock_checkpoints.clone(),
         });
 
-    let treasury_amount = ctx.accounts.treasury_token_account.amount;
-
-    ctx.accounts.vesting_account.total_token_obligation += total_amount as u64;
-
-    require!(
-        treasury_amount > ctx.accounts.vesting_account.total_token_obligation,
-        CustomError::NotEnoughTokensInTreasury
-    );
+    ctx.accounts.vesting_account.total_token_obligation = ctx
+        .accounts
+        .vesting_account
+        .total_token_obligation
+        .checked_add(total_amount)
+        .ok_or(CustomError::MathOverflow)?;
+    ctx.accounts.vesting_account.add_beneficiary_account()?;
+    ctx.accounts
+        .vesting_account
+        .assert_treasury_covers_obligation(ctx.accounts.treasury_token_account.amount)?;
 
     emit!(VestingScheduleInitialized {
         beneficiary: ctx.accounts.beneficiary.key(),
         vesting_account: ctx.accounts.vesting_account.key(),
+        grant_id,
         start_time,
         end_time,
         total_amount,
-        cliff_time
+        cliff_time,
+        cliff_amount,
+        initial_unlock_amount,
+        schedule_kind,
+        unlock_checkpoints
     });
     Ok(())
 }
 
+/// Checkpoints must be strictly ordered in time, never decrease, stay within the
+/// schedule and end at `total_amount`.
+fn validate_unlock_checkpoints(
+    unlock_checkpoints: &[UnlockCheckpoint],
+    start_time: i64,
+    end_time: i64,
+    total_amount: u64,
+) -> Result<()> {
+    require!(
+        !unlock_checkpoints.is_empty() && unlock_checkpoints.len() <= MAX_UNLOCK_CHECKPOINTS,
+        CustomError::InvalidUnlockCheckpoints
+    );
+
+    let mut previous: Option<&UnlockCheckpoint> = None;
+    for checkpoint in unlock_checkpoints {
+        require!(
+            checkpoint.timestamp >= start_time && checkpoint.timestamp <= end_time,
+            CustomError::InvalidUnlockCheckpoints
+        );
+        if let Some(previous) = previous {
+            require!(
+                checkpoint.timestamp > previous.timestamp
+                    && checkpoint.cumulative_amount >= previous.cumulative_amount,
+                CustomError::InvalidUnlockCheckpoints
+            );
+        }
+        previous = Some(checkpoint);
+    }
+
+    require!(
+        previous.is_some_and(|last| last.cumulative_amount == total_amount),
+        CustomError::InvalidUnlockCheckpoints
+    );
+    Ok(())
+}
+
 #[derive(Accounts)]
+#[instruction(grant_id: u64, params: VestingScheduleParams)]
 pub struct InitializeVestingSchedule<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub scheduler: Signer<'info>,
     #[account(
         mut,
-        constraint = vesting_account.admin == admin.key() @CustomError::UnAuthorized,
+        constraint = vesting_account.scheduler == scheduler.key() @CustomError::UnAuthorized,
         constraint = vesting_account.mint == mint.key() @CustomError::InvalidMint
     )]
     pub vesting_account: Account<'info, VestingAccount>,
@@ -69,9 +175,9 @@ pub struct InitializeVestingSchedule<'info> {
     pub beneficiary: SystemAccount<'info>,
     #[account(
         init,
-        payer = admin,
-        space = ANCHOR_DISCRIMINATOR_SIZE + BeneficiaryAccount::INIT_SPACE,
-        seeds = [b"beneficiary_vesting_account", beneficiary.key().as_ref(), vesting_account.key().as_ref()],
+        payer = scheduler,
+        space = BeneficiaryAccount::space(params.unlock_checkpoints.len()),
+        seeds = [b"beneficiary_vesting_account", beneficiary.key().as_ref(), vesting_account.key().as_ref(), &grant_id.to_le_bytes()],
         bump
     )]
     pub beneficiary_vesting_account: Account<'info, BeneficiaryAccount>,
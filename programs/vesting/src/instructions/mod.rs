@@ -7,11 +7,92 @@ pub use initialize_vesting_schedule::*;
 pub mod claim_vested_tokens;
 pub use claim_vested_tokens::*;
 
+pub mod claim_vested_tokens_to;
+pub use claim_vested_tokens_to::*;
+
+pub mod crank_claim_vested_tokens;
+pub use crank_claim_vested_tokens::*;
+
+pub mod set_crank_opt_out;
+pub use set_crank_opt_out::*;
+
 pub mod transfer_to_treasury;
 pub use transfer_to_treasury::*;
 
-pub mod change_admin;
-pub use change_admin::*;
+pub mod propose_admin;
+pub use propose_admin::*;
+
+pub mod accept_admin;
+pub use accept_admin::*;
+
+pub mod cancel_admin_proposal;
+pub use cancel_admin_proposal::*;
+
+pub mod set_roles;
+pub use set_roles::*;
 
 pub mod revoke_beneficiary_account;
 pub use revoke_beneficiary_account::*;
+
+pub mod withdraw_treasury_surplus;
+pub use withdraw_treasury_surplus::*;
+
+pub mod batch_claim_vested_tokens;
+pub use batch_claim_vested_tokens::*;
+
+pub mod queue_admin_action;
+pub use queue_admin_action::*;
+
+pub mod cancel_admin_action;
+pub use cancel_admin_action::*;
+
+pub mod set_admin_action_delay;
+pub use set_admin_action_delay::*;
+
+pub mod amend_vesting_schedule;
+pub use amend_vesting_schedule::*;
+
+pub mod cancel_vesting_schedule;
+pub use cancel_vesting_schedule::*;
+
+pub mod close_beneficiary_account;
+pub use close_beneficiary_account::*;
+
+pub mod set_grant_transfer_approval;
+pub use set_grant_transfer_approval::*;
+
+pub mod transfer_grant;
+pub use transfer_grant::*;
+
+pub mod suspend_beneficiary_account;
+pub use suspend_beneficiary_account::*;
+
+pub mod resume_beneficiary_account;
+pub use resume_beneficiary_account::*;
+
+pub mod close_vesting_account;
+pub use close_vesting_account::*;
+
+pub mod pause_vesting_account;
+pub use pause_vesting_account::*;
+
+pub mod unpause_vesting_account;
+pub use unpause_vesting_account::*;
+
+pub mod create_multisig;
+pub use create_multisig::*;
+
+pub mod create_multisig_proposal;
+pub use create_multisig_proposal::*;
+
+pub mod approve_multisig_proposal;
+pub use approve_multisig_proposal::*;
+
+pub mod execute_multisig_proposal;
+pub use execute_multisig_proposal::*;
+
+pub mod update_multisig;
+pub use update_multisig::*;
+
+pub mod cancel_multisig_proposal;
+pub use cancel_multisig_proposal::*;
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::CustomError, events::VestingAccountPaused, state::VestingAccount};
+
+pub fn pause_vesting_account(ctx: Context<PauseVestingAccount>) -> Result<()> {
+    let vesting_account = &mut ctx.accounts.vesting_account;
+    require!(!vesting_account.paused, CustomError::VestingAccountIsPaused);
+
+    vesting_account.paused = true;
+
+    emit!(VestingAccountPaused {
+        vesting_account: vesting_account.key(),
+        admin: ctx.accounts.admin.key()
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct PauseVestingAccount<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        constraint = vesting_account.admin == admin.key() @CustomError::UnAuthorized
+    )]
+    pub vesting_account: Account<'info, VestingAccount>,
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::CustomError,
+    events::AdminProposed,
+    state::{AdminAction, QueuedAdminAction, VestingAccount},
+    utils::consume_queued_admin_action,
+};
+
+pub fn propose_admin(ctx: Context<ProposeAdmin>) -> Result<()> {
+    consume_queued_admin_action(
+        &mut ctx.accounts.vesting_account,
+        ctx.accounts.queued_admin_action.as_ref(),
+        AdminAction::ChangeAdmin {
+            new_admin: ctx.accounts.new_admin.key(),
+        },
+        Clock::get()?.unix_timestamp,
+        None,
+    )?;
+
+    let vesting_account = &mut ctx.accounts.vesting_account;
+
+    vesting_account.pending_admin = Some(ctx.accounts.new_admin.key());
+
+    emit!(AdminProposed {
+        vesting_account: vesting_account.key(),
+        admin: ctx.accounts.admin.key(),
+        pending_admin: ctx.accounts.new_admin.key()
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        constraint = vesting_account.admin == admin.key() @CustomError::UnAuthorized
+    )]
+    pub vesting_account: Account<'info, VestingAccount>,
+    pub new_admin: SystemAccount<'info>,
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"queued_admin_action", vesting_account.key().as_ref(), &queued_admin_action.index.to_le_bytes()],
+        bump = queued_admin_action.bump,
+        has_one = vesting_account
+    )]
+    pub queued_admin_action: Option<Account<'info, QueuedAdminAction>>,
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constant::ANCHOR_DISCRIMINATOR_SIZE,
+    error::CustomError,
+    events::AdminActionQueued,
+    state::{AdminAction, BeneficiaryAccount, QueuedAdminAction, VestingAccount},
+};
+
+pub fn queue_admin_action(ctx: Context<QueueAdminAction>, action: AdminAction) -> Result<()> {
+    let vesting_account = &mut ctx.accounts.vesting_account;
+    require!(
+        action.authority(vesting_account) == ctx.accounts.authority.key(),
+        CustomError::UnAuthorized
+    );
+
+    let queued_at = Clock::get()?.unix_timestamp;
+    let index = vesting_account.queued_admin_action_count;
+    vesting_account.queued_admin_action_count =
+        index.checked_add(1).ok_or(CustomError::MathOverflow)?;
+    vesting_account.open_admin_action_count = vesting_account
+        .open_admin_action_count
+        .checked_add(1)
+        .ok_or(CustomError::MathOverflow)?;
+    if let Some(target) = action.beneficiary_vesting_account() {
+        let beneficiary_vesting_account = ctx
+            .accounts
+            .beneficiary_vesting_account
+            .as_mut()
+            .filter(|beneficiary_vesting_account| beneficiary_vesting_account.key() == target)
+            .ok_or(CustomError::InvalidAdminActionTarget)?;
+        beneficiary_vesting_account.add_admin_action()?;
+    }
+
+    ctx.accounts
+        .queued_admin_action
+        .set_inner(QueuedAdminAction {
+            vesting_account: vesting_account.key(),
+            index,
+            action: action.clone(),
+            queued_by: ctx.accounts.authority.key(),
+            queued_at,
+            bump: ctx.bumps.queued_admin_action,
+        });
+
+    emit!(AdminActionQueued {
+        vesting_account: ctx.accounts.vesting_account.key(),
+        queued_admin_action: ctx.accounts.queued_admin_action.key(),
+        action,
+        queued_by: ctx.accounts.authority.key(),
+        queued_at
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct QueueAdminAction<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub vesting_account: Account<'info, VestingAccount>,
+    #[account(
+        init,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR_SIZE + QueuedAdminAction::INIT_SPACE,
+        seeds = [b"queued_admin_action", vesting_account.key().as_ref(), &vesting_account.queued_admin_action_count.to_le_bytes()],
+        bump
+    )]
+    pub queued_admin_action: Account<'info, QueuedAdminAction>,
+    /// Required when the action targets a grant.
+    #[account(
+        mut,
+        has_one = vesting_account
+    )]
+    pub beneficiary_vesting_account: Option<Account<'info, BeneficiaryAccount>>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::CustomError,
+    events::BeneficiaryAccountResumed,
+    state::{BeneficiaryAccount, VestingAccount},
+};
+
+/// Ends the current suspension and pushes `end_time` back by its length.
+pub fn resume_beneficiary_account(ctx: Context<ResumeBeneficiaryAccount>) -> Result<()> {
+    let beneficiary_vesting_account = &mut ctx.accounts.beneficiary_vesting_account;
+    require!(
+        beneficiary_vesting_account.revoke_at.is_none(),
+        CustomError::AlreadyRevoked
+    );
+    let suspended_at = beneficiary_vesting_account
+        .suspended_at
+        .take()
+        .ok_or(CustomError::NotSuspended)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let suspended_seconds = now
+        .checked_sub(suspended_at)
+        .ok_or(CustomError::MathOverflow)?;
+    beneficiary_vesting_account.suspended_seconds = beneficiary_vesting_account
+        .suspended_seconds
+        .checked_add(suspended_seconds)
+        .ok_or(CustomError::MathOverflow)?;
+    beneficiary_vesting_account.end_time = beneficiary_vesting_account
+        .end_time
+        .checked_add(suspended_seconds)
+        .ok_or(CustomError::MathOverflow)?;
+
+    emit!(BeneficiaryAccountResumed {
+        vesting_account: ctx.accounts.vesting_account.key(),
+        beneficiary: ctx.accounts.beneficiary.key(),
+        grant_id: beneficiary_vesting_account.grant_id,
+        resumed_at: now,
+        suspended_seconds,
+        end_time: beneficiary_vesting_account.end_time
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ResumeBeneficiaryAccount<'info> {
+    pub revoker: Signer<'info>,
+    pub beneficiary: SystemAccount<'info>,
+    #[account(
+        constraint = vesting_account.revoker == revoker.key() @CustomError::UnAuthorized
+    )]
+    pub vesting_account: Account<'info, VestingAccount>,
+    #[account(
+        mut,
+        seeds = [b"beneficiary_vesting_account", beneficiary.key().as_ref(), vesting_account.key().as_ref(), &beneficiary_vesting_account.grant_id.to_le_bytes()],
+        bump = beneficiary_vesting_account.bump,
+        has_one = beneficiary,
+        has_one = vesting_account
+    )]
+    pub beneficiary_vesting_account: Account<'info, BeneficiaryAccount>,
+}
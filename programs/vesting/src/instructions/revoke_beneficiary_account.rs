@@ -1,19 +1,80 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked};
 
 use crate::{
-    state::{BeneficiaryAccount, VestingAccount},
+    events::BeneficiaryAccountRevoked,
+    state::{AdminAction, BeneficiaryAccount, QueuedAdminAction, VestingAccount},
+    utils::{consume_queued_admin_action, transfer_from_treasury},
     CustomError,
 };
 
-pub fn handler(ctx: Context<RevokeAccount>) -> Result<()> {
+pub fn revoke_beneficiary_account(ctx: Context<RevokeAccount>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    consume_queued_admin_action(
+        &mut ctx.accounts.vesting_account,
+        ctx.accounts.queued_admin_action.as_ref(),
+        AdminAction::Revoke {
+            beneficiary_vesting_account: ctx.accounts.beneficiary_vesting_account.key(),
+        },
+        now,
+        Some(&mut ctx.accounts.beneficiary_vesting_account),
+    )?;
+
     let beneficiary_vesting_account = &mut ctx.accounts.beneficiary_vesting_account;
     require!(
-        beneficiary_vesting_account.total_amount > beneficiary_vesting_account.total_withdrawn,
+        beneficiary_vesting_account.revoke_at.is_none(),
+        CustomError::AlreadyRevoked
+    );
+
+    let vested_amount = beneficiary_vesting_account.vested_amount(now)?;
+    require!(
+        beneficiary_vesting_account.total_amount > vested_amount,
         CustomError::RevokeNotNeeded
     );
 
-    let now = Clock::get()?.unix_timestamp;
+    let vested_unclaimed_amount =
+        vested_amount.saturating_sub(beneficiary_vesting_account.total_withdrawn);
+    let unvested_amount = beneficiary_vesting_account
+        .total_amount
+        .checked_sub(vested_amount)
+        .ok_or(CustomError::MathOverflow)?;
+
     beneficiary_vesting_account.revoke_at = Some(now);
+    ctx.accounts
+        .vesting_account
+        .release_obligation(unvested_amount)?;
+
+    let transfer_token_cpi_account = TransferChecked {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.treasury_token_account.to_account_info(),
+        to: ctx.accounts.refund_token_account.to_account_info(),
+        authority: ctx.accounts.treasury_token_account.to_account_info(),
+    };
+    let vesting_account_key = ctx.accounts.vesting_account.key();
+
+    transfer_from_treasury(
+        ctx.accounts.token_program.to_account_info(),
+        transfer_token_cpi_account,
+        vesting_account_key,
+        ctx.accounts.vesting_account.treasury_bump,
+        unvested_amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    ctx.accounts.treasury_token_account.reload()?;
+    ctx.accounts
+        .vesting_account
+        .assert_treasury_covers_obligation(ctx.accounts.treasury_token_account.amount)?;
+
+    emit!(BeneficiaryAccountRevoked {
+        vesting_account: vesting_account_key,
+        beneficiary: ctx.accounts.beneficiary.key(),
+        grant_id: ctx.accounts.beneficiary_vesting_account.grant_id,
+        revoked_at: now,
+        vested_unclaimed_amount,
+        unvested_amount,
+        refund_token_account: ctx.accounts.refund_token_account.key(),
+    });
 
     Ok(())
 }
@@ -21,13 +82,45 @@ pub fn handler(ctx: Context<RevokeAccount>) -> Result<()> {
 #[derive(Accounts)]
 pub struct RevokeAccount<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub revoker: Signer<'info>,
     pub beneficiary: SystemAccount<'info>,
+    #[account(
+        mut,
+        constraint = vesting_account.revoker == revoker.key() @CustomError::UnAuthorized,
+        constraint = !vesting_account.paused @CustomError::VestingAccountIsPaused,
+        has_one = treasury_token_account,
+        has_one = mint
+    )]
     pub vesting_account: Account<'info, VestingAccount>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [b"vesting_treasury", vesting_account.key().as_ref()],
+        bump = vesting_account.treasury_bump
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
     #[account(
         mut,
-        seeds = [b"beneficiary_vesting_account", beneficiary.key().as_ref(), vesting_account.key().as_ref()],
-        bump = beneficiary_vesting_account.bump
+        token::mint = mint,
+        token::token_program = token_program,
+        constraint = refund_token_account.owner == vesting_account.treasurer @CustomError::InvalidRefundAccount
+    )]
+    pub refund_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"beneficiary_vesting_account", beneficiary.key().as_ref(), vesting_account.key().as_ref(), &beneficiary_vesting_account.grant_id.to_le_bytes()],
+        bump = beneficiary_vesting_account.bump,
+        has_one = beneficiary,
+        has_one = vesting_account
     )]
     pub beneficiary_vesting_account: Account<'info, BeneficiaryAccount>,
+    #[account(
+        mut,
+        close = revoker,
+        seeds = [b"queued_admin_action", vesting_account.key().as_ref(), &queued_admin_action.index.to_le_bytes()],
+        bump = queued_admin_action.bump,
+        has_one = vesting_account
+    )]
+    pub queued_admin_action: Option<Account<'info, QueuedAdminAction>>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constant::MAX_ADMIN_ACTION_DELAY,
+    error::CustomError,
+    events::AdminActionDelayUpdated,
+    state::{AdminAction, QueuedAdminAction, VestingAccount},
+    utils::consume_queued_admin_action,
+};
+
+pub fn set_admin_action_delay(ctx: Context<SetAdminActionDelay>, delay: i64) -> Result<()> {
+    require!(
+        (0..=MAX_ADMIN_ACTION_DELAY).contains(&delay),
+        CustomError::InvalidAdminActionDelay
+    );
+    consume_queued_admin_action(
+        &mut ctx.accounts.vesting_account,
+        ctx.accounts.queued_admin_action.as_ref(),
+        AdminAction::SetAdminActionDelay { delay },
+        Clock::get()?.unix_timestamp,
+        None,
+    )?;
+
+    let vesting_account = &mut ctx.accounts.vesting_account;
+    let old_delay = vesting_account.admin_action_delay;
+    vesting_account.admin_action_delay = delay;
+
+    emit!(AdminActionDelayUpdated {
+        vesting_account: vesting_account.key(),
+        old_delay,
+        new_delay: delay
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetAdminActionDelay<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        constraint = vesting_account.admin == admin.key() @CustomError::UnAuthorized
+    )]
+    pub vesting_account: Account<'info, VestingAccount>,
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"queued_admin_action", vesting_account.key().as_ref(), &queued_admin_action.index.to_le_bytes()],
+        bump = queued_admin_action.bump,
+        has_one = vesting_account
+    )]
+    pub queued_admin_action: Option<Account<'info, QueuedAdminAction>>,
+}
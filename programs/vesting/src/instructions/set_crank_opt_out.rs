@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::CrankOptOutUpdated,
+    state::{BeneficiaryAccount, VestingAccount},
+};
+
+pub fn set_crank_opt_out(ctx: Context<SetCrankOptOut>, opt_out: bool) -> Result<()> {
+    let beneficiary_vesting_account = &mut ctx.accounts.beneficiary_vesting_account;
+
+    beneficiary_vesting_account.crank_opt_out = opt_out;
+
+    emit!(CrankOptOutUpdated {
+        vesting_account: ctx.accounts.vesting_account.key(),
+        beneficiary: ctx.accounts.beneficiary.key(),
+        grant_id: beneficiary_vesting_account.grant_id,
+        opt_out
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetCrankOptOut<'info> {
+    pub beneficiary: Signer<'info>,
+    pub vesting_account: Account<'info, VestingAccount>,
+    #[account(
+        mut,
+        seeds = [b"beneficiary_vesting_account", beneficiary.key().as_ref(), vesting_account.key().as_ref(), &beneficiary_vesting_account.grant_id.to_le_bytes()],
+        bump = beneficiary_vesting_account.bump,
+        has_one = beneficiary,
+        has_one = vesting_account
+    )]
+    pub beneficiary_vesting_account: Account<'info, BeneficiaryAccount>,
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::CustomError, events::GrantTransferApprovalUpdated, state::VestingAccount};
+
+pub fn set_grant_transfer_approval(
+    ctx: Context<SetGrantTransferApproval>,
+    required: bool,
+) -> Result<()> {
+    let vesting_account = &mut ctx.accounts.vesting_account;
+
+    vesting_account.grant_transfer_requires_approval = required;
+
+    emit!(GrantTransferApprovalUpdated {
+        vesting_account: vesting_account.key(),
+        admin: ctx.accounts.admin.key(),
+        required
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetGrantTransferApproval<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        constraint = vesting_account.admin == admin.key() @CustomError::UnAuthorized
+    )]
+    pub vesting_account: Account<'info, VestingAccount>,
+}
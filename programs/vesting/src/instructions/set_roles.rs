@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::CustomError, events::RolesUpdated, state::VestingAccount};
+
+pub fn set_roles(
+    ctx: Context<SetRoles>,
+    scheduler: Pubkey,
+    revoker: Pubkey,
+    treasurer: Pubkey,
+) -> Result<()> {
+    let vesting_account = &mut ctx.accounts.vesting_account;
+
+    vesting_account.scheduler = scheduler;
+    vesting_account.revoker = revoker;
+    vesting_account.treasurer = treasurer;
+
+    emit!(RolesUpdated {
+        vesting_account: vesting_account.key(),
+        admin: ctx.accounts.admin.key(),
+        scheduler,
+        revoker,
+        treasurer
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetRoles<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        constraint = vesting_account.admin == admin.key() @CustomError::UnAuthorized
+    )]
+    pub vesting_account: Account<'info, VestingAccount>,
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::CustomError,
+    events::BeneficiaryAccountSuspended,
+    state::{AdminAction, BeneficiaryAccount, QueuedAdminAction, VestingAccount},
+    utils::consume_queued_admin_action,
+};
+
+pub fn suspend_beneficiary_account(ctx: Context<SuspendBeneficiaryAccount>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    consume_queued_admin_action(
+        &mut ctx.accounts.vesting_account,
+        ctx.accounts.queued_admin_action.as_ref(),
+        AdminAction::Suspend {
+            beneficiary_vesting_account: ctx.accounts.beneficiary_vesting_account.key(),
+        },
+        now,
+        Some(&mut ctx.accounts.beneficiary_vesting_account),
+    )?;
+
+    let beneficiary_vesting_account = &mut ctx.accounts.beneficiary_vesting_account;
+    require!(
+        beneficiary_vesting_account.revoke_at.is_none(),
+        CustomError::AlreadyRevoked
+    );
+    require!(
+        beneficiary_vesting_account.suspended_at.is_none(),
+        CustomError::AlreadySuspended
+    );
+
+    require!(
+        beneficiary_vesting_account.vested_amount(now)? < beneficiary_vesting_account.total_amount,
+        CustomError::VestingAlreadyComplete
+    );
+
+    beneficiary_vesting_account.suspended_at = Some(now);
+
+    emit!(BeneficiaryAccountSuspended {
+        vesting_account: ctx.accounts.vesting_account.key(),
+        beneficiary: ctx.accounts.beneficiary.key(),
+        grant_id: beneficiary_vesting_account.grant_id,
+        suspended_at: now
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SuspendBeneficiaryAccount<'info> {
+    #[account(mut)]
+    pub revoker: Signer<'info>,
+    pub beneficiary: SystemAccount<'info>,
+    #[account(
+        mut,
+        constraint = vesting_account.revoker == revoker.key() @CustomError::UnAuthorized
+    )]
+    pub vesting_account: Account<'info, VestingAccount>,
+    #[account(
+        mut,
+        seeds = [b"beneficiary_vesting_account", beneficiary.key().as_ref(), vesting_account.key().as_ref(), &beneficiary_vesting_account.grant_id.to_le_bytes()],
+        bump = beneficiary_vesting_account.bump,
+        has_one = beneficiary,
+        has_one = vesting_account
+    )]
+    pub beneficiary_vesting_account: Account<'info, BeneficiaryAccount>,
+    #[account(
+        mut,
+        close = revoker,
+        seeds = [b"queued_admin_action", vesting_account.key().as_ref(), &queued_admin_action.index.to_le_bytes()],
+        bump = queued_admin_action.bump,
+        has_one = vesting_account
+    )]
+    pub queued_admin_action: Option<Account<'info, QueuedAdminAction>>,
+}
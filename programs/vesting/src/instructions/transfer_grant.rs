@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::CustomError,
+    events::GrantTransferred,
+    state::{BeneficiaryAccount, VestingAccount},
+    utils::create_program_account,
+};
+
+/// Moves a grant, including what has already been withdrawn, to a new beneficiary wallet.
+pub fn transfer_grant(ctx: Context<TransferGrant>) -> Result<()> {
+    let vesting_account = &ctx.accounts.vesting_account;
+    require!(
+        ctx.accounts
+            .beneficiary_vesting_account
+            .open_admin_action_count
+            == 0,
+        CustomError::GrantHasQueuedAdminActions
+    );
+    let approver = if vesting_account.grant_transfer_requires_approval {
+        let approver = ctx
+            .accounts
+            .approver
+            .as_ref()
+            .map(|approver| approver.key());
+        require!(
+            approver == Some(vesting_account.admin),
+            CustomError::GrantTransferNeedsApproval
+        );
+        approver
+    } else {
+        None
+    };
+
+    let new_beneficiary_vesting_account = &ctx.accounts.new_beneficiary_vesting_account;
+    require!(
+        new_beneficiary_vesting_account.data_is_empty(),
+        CustomError::GrantAlreadyExists
+    );
+
+    let mut migrated_account = ctx
+        .accounts
+        .beneficiary_vesting_account
+        .clone()
+        .into_inner();
+    migrated_account.beneficiary = ctx.accounts.new_beneficiary.key();
+    migrated_account.rent_payer = ctx.accounts.beneficiary.key();
+    migrated_account.bump = ctx.bumps.new_beneficiary_vesting_account;
+
+    let new_beneficiary_key = ctx.accounts.new_beneficiary.key();
+    let vesting_account_key = vesting_account.key();
+    let grant_id_bytes = migrated_account.grant_id.to_le_bytes();
+    create_program_account(
+        &ctx.accounts.beneficiary.to_account_info(),
+        &new_beneficiary_vesting_account.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        BeneficiaryAccount::space(migrated_account.unlock_checkpoints.len()),
+        &[
+            b"beneficiary_vesting_account",
+            new_beneficiary_key.as_ref(),
+            vesting_account_key.as_ref(),
+            &grant_id_bytes,
+            &[migrated_account.bump],
+        ],
+    )?;
+    migrated_account
+        .try_serialize(&mut &mut new_beneficiary_vesting_account.try_borrow_mut_data()?[..])?;
+
+    emit!(GrantTransferred {
+        vesting_account: vesting_account.key(),
+        grant_id: ctx.accounts.beneficiary_vesting_account.grant_id,
+        old_beneficiary: ctx.accounts.beneficiary.key(),
+        new_beneficiary: ctx.accounts.new_beneficiary.key(),
+        approver
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct TransferGrant<'info> {
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+    #[account(
+        constraint = new_beneficiary.key() != beneficiary.key() @CustomError::InvalidGrantTransfer
+    )]
+    pub new_beneficiary: SystemAccount<'info>,
+    pub approver: Option<Signer<'info>>,
+    pub vesting_account: Account<'info, VestingAccount>,
+    /// CHECK: Paid for the old beneficiary account and gets its rent back.
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [b"beneficiary_vesting_account", beneficiary.key().as_ref(), vesting_account.key().as_ref(), &beneficiary_vesting_account.grant_id.to_le_bytes()],
+        bump = beneficiary_vesting_account.bump,
+        has_one = beneficiary,
+        has_one = vesting_account,
+        has_one = rent_payer
+    )]
+    pub beneficiary_vesting_account: Account<'info, BeneficiaryAccount>,
+    /// CHECK: Created in the handler once it is known not to hold a grant already.
+    #[account(
+        mut,
+        seeds = [b"beneficiary_vesting_account", new_beneficiary.key().as_ref(), vesting_account.key().as_ref(), &beneficiary_vesting_account.grant_id.to_le_bytes()],
+        bump
+    )]
+    pub new_beneficiary_vesting_account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::CustomError, events::VestingAccountUnpaused, state::VestingAccount};
+
+pub fn unpause_vesting_account(ctx: Context<UnpauseVestingAccount>) -> Result<()> {
+    let vesting_account = &mut ctx.accounts.vesting_account;
+    require!(vesting_account.paused, CustomError::VestingAccountNotPaused);
+
+    vesting_account.paused = false;
+
+    emit!(VestingAccountUnpaused {
+        vesting_account: vesting_account.key(),
+        admin: ctx.accounts.admin.key()
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UnpauseVestingAccount<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        constraint = vesting_account.admin == admin.key() @CustomError::UnAuthorized
+    )]
+    pub vesting_account: Account<'info, VestingAccount>,
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::CustomError, events::MultisigUpdated, state::Multisig};
+
+/// Replaces the signer set and threshold. Only the multisig authority can sign for this,
+/// so it has to go through a proposal approved under the current signers.
+pub fn update_multisig(
+    ctx: Context<UpdateMultisig>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    Multisig::validate_config(&signers, threshold)?;
+
+    let multisig = &mut ctx.accounts.multisig;
+    multisig.signers = signers.clone();
+    multisig.threshold = threshold;
+    multisig.config_version = multisig
+        .config_version
+        .checked_add(1)
+        .ok_or(CustomError::MathOverflow)?;
+
+    emit!(MultisigUpdated {
+        multisig: multisig.key(),
+        signers,
+        threshold,
+        config_version: multisig.config_version
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateMultisig<'info> {
+    #[account(
+        seeds = [b"multisig_authority", multisig.key().as_ref()],
+        bump = multisig.authority_bump
+    )]
+    pub multisig_authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"multisig", multisig.vesting_account.as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+}
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::{
+    error::CustomError,
+    events::TreasurySurplusWithdrawn,
+    state::{AdminAction, QueuedAdminAction, VestingAccount},
+    utils::{consume_queued_admin_action, transfer_from_treasury},
+};
+
+pub fn withdraw_treasury_surplus(ctx: Context<WithdrawTreasurySurplus>) -> Result<()> {
+    consume_queued_admin_action(
+        &mut ctx.accounts.vesting_account,
+        ctx.accounts.queued_admin_action.as_ref(),
+        AdminAction::WithdrawSurplus {
+            destination_token_account: ctx.accounts.destination_token_account.key(),
+        },
+        Clock::get()?.unix_timestamp,
+        None,
+    )?;
+
+    let surplus_amount = ctx
+        .accounts
+        .treasury_token_account
+        .amount
+        .saturating_sub(ctx.accounts.vesting_account.total_token_obligation);
+
+    require!(surplus_amount > 0, CustomError::NoSurplusToWithdraw);
+
+    let transfer_token_cpi_account = TransferChecked {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.treasury_token_account.to_account_info(),
+        to: ctx.accounts.destination_token_account.to_account_info(),
+        authority: ctx.accounts.treasury_token_account.to_account_info(),
+    };
+    let vesting_account_key = ctx.accounts.vesting_account.key();
+
+    transfer_from_treasury(
+        ctx.accounts.token_program.to_account_info(),
+        transfer_token_cpi_account,
+        vesting_account_key,
+        ctx.accounts.vesting_account.treasury_bump,
+        surplus_amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    ctx.accounts.treasury_token_account.reload()?;
+    ctx.accounts
+        .vesting_account
+        .assert_treasury_covers_obligation(ctx.accounts.treasury_token_account.amount)?;
+
+    emit!(TreasurySurplusWithdrawn {
+        vesting_account: vesting_account_key,
+        treasurer: ctx.accounts.treasurer.key(),
+        destination_token_account: ctx.accounts.destination_token_account.key(),
+        amount: surplus_amount
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawTreasurySurplus<'info> {
+    #[account(mut)]
+    pub treasurer: Signer<'info>,
+    #[account(
+        mut,
+        constraint = vesting_account.treasurer == treasurer.key() @CustomError::UnAuthorized,
+        constraint = !vesting_account.paused @CustomError::VestingAccountIsPaused,
+        has_one = treasury_token_account,
+        has_one = mint
+    )]
+    pub vesting_account: Account<'info, VestingAccount>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [b"vesting_treasury", vesting_account.key().as_ref()],
+        bump = vesting_account.treasury_bump
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program
+    )]
+    pub destination_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        close = treasurer,
+        seeds = [b"queued_admin_action", vesting_account.key().as_ref(), &queued_admin_action.index.to_le_bytes()],
+        bump = queued_admin_action.bump,
+        has_one = vesting_account
+    )]
+    pub queued_admin_action: Option<Account<'info, QueuedAdminAction>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
@@ -5,12 +5,12 @@ pub mod error;
 pub mod events;
 pub mod instructions;
 pub mod state;
+pub mod utils;
 
 use anchor_lang::prelude::*;
 
 use constant::*;
 use error::*;
-use events::*;
 use instructions::*;
 use state::*;
 
@@ -25,31 +25,139 @@ pub mod vesting {
         id: u64,
         company_name: String,
     ) -> Result<()> {
-        instructions::create_vesting_account::handler(ctx, id, company_name)
+        instructions::create_vesting_account::create_vesting_account(ctx, id, company_name)
     }
 
     pub fn initialize_vesting_schedule(
         ctx: Context<InitializeVestingSchedule>,
-        start_time: i64,
-        end_time: i64,
-        total_amount: i64,
-        cliff_time: i64,
+        grant_id: u64,
+        params: VestingScheduleParams,
     ) -> Result<()> {
-        instructions::initialize_vesting_schedule::handler(
+        instructions::initialize_vesting_schedule::initialize_vesting_schedule(
+            ctx, grant_id, params,
+        )
+    }
+
+    pub fn claim_vested_tokens(ctx: Context<ClaimTokens>) -> Result<()> {
+        claim_vested_tokens::claim_vested_tokens(ctx)
+    }
+
+    pub fn claim_vested_tokens_to(ctx: Context<ClaimTokensTo>) -> Result<()> {
+        claim_vested_tokens_to::claim_vested_tokens_to(ctx)
+    }
+
+    pub fn crank_claim_vested_tokens(ctx: Context<CrankClaimTokens>) -> Result<()> {
+        crank_claim_vested_tokens::crank_claim_vested_tokens(ctx)
+    }
+
+    pub fn set_crank_opt_out(ctx: Context<SetCrankOptOut>, opt_out: bool) -> Result<()> {
+        set_crank_opt_out::set_crank_opt_out(ctx, opt_out)
+    }
+
+    pub fn batch_claim_vested_tokens<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchClaimTokens<'info>>,
+    ) -> Result<()> {
+        batch_claim_vested_tokens::batch_claim_vested_tokens(ctx)
+    }
+
+    pub fn set_admin_action_delay(ctx: Context<SetAdminActionDelay>, delay: i64) -> Result<()> {
+        set_admin_action_delay::set_admin_action_delay(ctx, delay)
+    }
+
+    pub fn queue_admin_action(ctx: Context<QueueAdminAction>, action: AdminAction) -> Result<()> {
+        queue_admin_action::queue_admin_action(ctx, action)
+    }
+
+    pub fn cancel_admin_action(ctx: Context<CancelAdminAction>) -> Result<()> {
+        cancel_admin_action::cancel_admin_action(ctx)
+    }
+
+    pub fn amend_vesting_schedule(
+        ctx: Context<AmendVestingSchedule>,
+        params: AmendVestingScheduleParams,
+    ) -> Result<()> {
+        amend_vesting_schedule::amend_vesting_schedule(ctx, params)
+    }
+
+    pub fn cancel_vesting_schedule(ctx: Context<CancelVestingSchedule>) -> Result<()> {
+        cancel_vesting_schedule::cancel_vesting_schedule(ctx)
+    }
+
+    pub fn close_beneficiary_account(ctx: Context<CloseBeneficiaryAccount>) -> Result<()> {
+        close_beneficiary_account::close_beneficiary_account(ctx)
+    }
+
+    pub fn set_grant_transfer_approval(
+        ctx: Context<SetGrantTransferApproval>,
+        required: bool,
+    ) -> Result<()> {
+        set_grant_transfer_approval::set_grant_transfer_approval(ctx, required)
+    }
+
+    pub fn transfer_grant(ctx: Context<TransferGrant>) -> Result<()> {
+        transfer_grant::transfer_grant(ctx)
+    }
+
+    pub fn suspend_beneficiary_account(ctx: Context<SuspendBeneficiaryAccount>) -> Result<()> {
+        suspend_beneficiary_account::suspend_beneficiary_account(ctx)
+    }
+
+    pub fn resume_beneficiary_account(ctx: Context<ResumeBeneficiaryAccount>) -> Result<()> {
+        resume_beneficiary_account::resume_beneficiary_account(ctx)
+    }
+
+    pub fn close_vesting_account(ctx: Context<CloseVestingAccount>) -> Result<()> {
+        close_vesting_account::close_vesting_account(ctx)
+    }
+
+    pub fn pause_vesting_account(ctx: Context<PauseVestingAccount>) -> Result<()> {
+        pause_vesting_account::pause_vesting_account(ctx)
+    }
+
+    pub fn unpause_vesting_account(ctx: Context<UnpauseVestingAccount>) -> Result<()> {
+        unpause_vesting_account::unpause_vesting_account(ctx)
+    }
+
+    pub fn create_multisig(
+        ctx: Context<CreateMultisig>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        create_multisig::create_multisig(ctx, signers, threshold)
+    }
+
+    pub fn create_multisig_proposal(
+        ctx: Context<CreateMultisigProposal>,
+        instruction_accounts: Vec<ProposalAccountMeta>,
+        instruction_data: Vec<u8>,
+    ) -> Result<()> {
+        create_multisig_proposal::create_multisig_proposal(
             ctx,
-            start_time,
-            end_time,
-            total_amount,
-            cliff_time,
+            instruction_accounts,
+            instruction_data,
         )
     }
 
-    pub fn claim_vested_tokens(
-        ctx: Context<ClaimTokens>,
-        _company_name: String,
-        _id: u64,
+    pub fn approve_multisig_proposal(ctx: Context<ApproveMultisigProposal>) -> Result<()> {
+        approve_multisig_proposal::approve_multisig_proposal(ctx)
+    }
+
+    pub fn execute_multisig_proposal<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteMultisigProposal<'info>>,
+    ) -> Result<()> {
+        execute_multisig_proposal::execute_multisig_proposal(ctx)
+    }
+
+    pub fn update_multisig(
+        ctx: Context<UpdateMultisig>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
     ) -> Result<()> {
-        claim_vested_tokens::handler(ctx)
+        update_multisig::update_multisig(ctx, signers, threshold)
+    }
+
+    pub fn cancel_multisig_proposal(ctx: Context<CancelMultisigProposal>) -> Result<()> {
+        cancel_multisig_proposal::cancel_multisig_proposal(ctx)
     }
 
     pub fn transfer_tokens_to_treasury(
@@ -59,12 +167,33 @@ pub mod vesting {
         transfer_to_treasury(ctx, amount)
     }
 
-    pub fn change_admin(ctx: Context<ChangeAdmin>) -> Result<()> {
-        change_admin::handler(ctx)
+    pub fn propose_admin(ctx: Context<ProposeAdmin>) -> Result<()> {
+        propose_admin::propose_admin(ctx)
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        accept_admin::accept_admin(ctx)
+    }
+
+    pub fn cancel_admin_proposal(ctx: Context<CancelAdminProposal>) -> Result<()> {
+        cancel_admin_proposal::cancel_admin_proposal(ctx)
+    }
+
+    pub fn set_roles(
+        ctx: Context<SetRoles>,
+        scheduler: Pubkey,
+        revoker: Pubkey,
+        treasurer: Pubkey,
+    ) -> Result<()> {
+        set_roles::set_roles(ctx, scheduler, revoker, treasurer)
     }
 
     pub fn revoke_beneficiary_account(ctx: Context<RevokeAccount>) -> Result<()> {
-        revoke_beneficiary_account::handler(ctx)
+        revoke_beneficiary_account::revoke_beneficiary_account(ctx)
+    }
+
+    pub fn withdraw_treasury_surplus(ctx: Context<WithdrawTreasurySurplus>) -> Result<()> {
+        withdraw_treasury_surplus::withdraw_treasury_surplus(ctx)
     }
 }
 
@@ -1,15 +1,207 @@
-use anchor_lang::prelude::*;
+use anchor_lang::prelude::{borsh, *};
+
+use crate::{constant::ANCHOR_DISCRIMINATOR_SIZE, error::CustomError};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum ScheduleKind {
+    /// Vests continuously between `start_time` and `end_time`.
+    Linear,
+    /// Vests in whole steps of `period_seconds` counted from the start of the linear portion.
+    Periodic { period_seconds: i64 },
+    /// Unlocks in steps following `unlock_checkpoints`; checkpoints before `cliff_time`
+    /// only unlock once the cliff passes.
+    Piecewise,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub struct UnlockCheckpoint {
+    pub timestamp: i64,
+    /// Total amount unlocked once `timestamp` is reached.
+    pub cumulative_amount: u64,
+}
 
 #[account]
 #[derive(InitSpace)]
 pub struct BeneficiaryAccount {
     pub beneficiary: Pubkey,
     pub vesting_account: Pubkey,
+    /// Distinguishes multiple grants to the same beneficiary under one vesting account.
+    pub grant_id: u64,
     pub start_time: i64,
+    /// Pushed back by every completed suspension; the other schedule times are not.
     pub end_time: i64,
-    pub total_amount: i64,
-    pub total_withdrawn: i64,
+    pub total_amount: u64,
+    pub total_withdrawn: u64,
     pub cliff_time: i64,
+    pub cliff_amount: u64,
+    pub initial_unlock_amount: u64,
     pub revoke_at: Option<i64>,
+    /// Start of the current suspension, during which nothing accrues.
+    pub suspended_at: Option<i64>,
+    /// Total length of completed suspensions.
+    pub suspended_seconds: i64,
+    pub schedule_kind: ScheduleKind,
+    /// Receives the rent back when the account is closed.
+    pub rent_payer: Pubkey,
+    /// Stops anyone other than the beneficiary from claiming on their behalf.
+    pub crank_opt_out: bool,
+    /// Queued admin actions targeting this grant that have not been executed or cancelled yet.
+    pub open_admin_action_count: u64,
     pub bump: u8,
+    /// Sized at creation, so only piecewise schedules pay rent for it.
+    #[max_len(0)]
+    pub unlock_checkpoints: Vec<UnlockCheckpoint>,
+}
+
+impl BeneficiaryAccount {
+    /// Account space needed for a schedule with `checkpoint_count` unlock checkpoints.
+    pub fn space(checkpoint_count: usize) -> usize {
+        ANCHOR_DISCRIMINATOR_SIZE
+            + BeneficiaryAccount::INIT_SPACE
+            + checkpoint_count * UnlockCheckpoint::INIT_SPACE
+    }
+
+    /// Amount vested as of `timestamp`, never accruing past `revoke_at` or while suspended.
+    pub fn vested_amount(&self, timestamp: i64) -> Result<u64> {
+        let effective_time = match self.revoke_at {
+            Some(revoke_at) => timestamp.min(revoke_at),
+            None => timestamp,
+        };
+        let effective_time = self.accrual_time(effective_time)?;
+
+        match self.schedule_kind {
+            ScheduleKind::Linear => self.interpolated_vested_amount(effective_time, None),
+            ScheduleKind::Periodic { period_seconds } => {
+                self.interpolated_vested_amount(effective_time, Some(period_seconds))
+            }
+            ScheduleKind::Piecewise => Ok(self.checkpoint_vested_amount(effective_time)),
+        }
+    }
+
+    /// Vested amount as of `timestamp` that has not been withdrawn yet.
+    pub fn claimable_amount(&self, timestamp: i64) -> Result<u64> {
+        Ok(self
+            .vested_amount(timestamp)?
+            .saturating_sub(self.total_withdrawn))
+    }
+
+    /// True once everything this grant will ever vest has been withdrawn.
+    pub fn is_settled(&self, timestamp: i64) -> Result<bool> {
+        let final_amount = match self.revoke_at {
+            Some(_) => self.vested_amount(timestamp)?,
+            None => self.total_amount,
+        };
+        Ok(self.total_withdrawn >= final_amount)
+    }
+
+    pub fn add_admin_action(&mut self) -> Result<()> {
+        self.open_admin_action_count = self
+            .open_admin_action_count
+            .checked_add(1)
+            .ok_or(CustomError::MathOverflow)?;
+        Ok(())
+    }
+
+    pub fn remove_admin_action(&mut self) -> Result<()> {
+        self.open_admin_action_count = self
+            .open_admin_action_count
+            .checked_sub(1)
+            .ok_or(CustomError::MathOverflow)?;
+        Ok(())
+    }
+
+    pub fn record_withdrawal(&mut self, amount: u64) -> Result<()> {
+        self.total_withdrawn = self
+            .total_withdrawn
+            .checked_add(amount)
+            .ok_or(CustomError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Maps `timestamp` onto the original schedule by dropping suspended intervals.
+    /// Only valid for timestamps after the last resume, which holds for the current time.
+    fn accrual_time(&self, timestamp: i64) -> Result<i64> {
+        let timestamp = match self.suspended_at {
+            Some(suspended_at) => timestamp.min(suspended_at),
+            None => timestamp,
+        };
+        timestamp
+            .checked_sub(self.suspended_seconds)
+            .ok_or(error!(CustomError::MathOverflow))
+    }
+
+    /// `end_time` as it was before any suspension pushed it back.
+    fn schedule_end_time(&self) -> Result<i64> {
+        self.end_time
+            .checked_sub(self.suspended_seconds)
+            .ok_or(error!(CustomError::MathOverflow))
+    }
+
+    fn interpolated_vested_amount(
+        &self,
+        effective_time: i64,
+        period_seconds: Option<i64>,
+    ) -> Result<u64> {
+        if effective_time < self.start_time {
+            return Ok(0);
+        }
+
+        if effective_time < self.cliff_time {
+            return Ok(self.initial_unlock_amount);
+        }
+
+        let end_time = self.schedule_end_time()?;
+        if effective_time >= end_time {
+            return Ok(self.total_amount);
+        }
+
+        // A lump sum at the cliff moves the start of the linear portion to the cliff,
+        // otherwise everything accrued since `start_time` unlocks when the cliff passes.
+        let unlocked_amount = self
+            .initial_unlock_amount
+            .checked_add(self.cliff_amount)
+            .ok_or(CustomError::MathOverflow)?;
+        let linear_amount = self
+            .total_amount
+            .checked_sub(unlocked_amount)
+            .ok_or(CustomError::MathOverflow)?;
+        let linear_start_time = if self.cliff_amount > 0 {
+            self.cliff_time
+        } else {
+            self.start_time
+        };
+
+        let total_vesting_time = end_time
+            .checked_sub(linear_start_time)
+            .ok_or(CustomError::MathOverflow)?;
+        let time_since_start = effective_time
+            .checked_sub(linear_start_time)
+            .ok_or(CustomError::MathOverflow)?;
+        let time_since_start = match period_seconds {
+            Some(period_seconds) => time_since_start - time_since_start % period_seconds,
+            None => time_since_start,
+        };
+
+        let vested_amount = u128::from(linear_amount)
+            .checked_mul(time_since_start as u128)
+            .ok_or(CustomError::MathOverflow)?
+            .checked_div(total_vesting_time as u128)
+            .ok_or(CustomError::MathOverflow)?
+            .checked_add(u128::from(unlocked_amount))
+            .ok_or(CustomError::MathOverflow)?;
+
+        u64::try_from(vested_amount).map_err(|_| error!(CustomError::MathOverflow))
+    }
+
+    fn checkpoint_vested_amount(&self, effective_time: i64) -> u64 {
+        if effective_time < self.cliff_time {
+            return 0;
+        }
+
+        self.unlock_checkpoints
+            .iter()
+            .take_while(|checkpoint| checkpoint.timestamp <= effective_time)
+            .last()
+            .map_or(0, |checkpoint| checkpoint.cumulative_amount)
+    }
 }
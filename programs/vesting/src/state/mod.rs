@@ -3,3 +3,9 @@ pub use vesting_account::*;
 
 pub mod beneficiary_account;
 pub use beneficiary_account::*;
+
+pub mod multisig;
+pub use multisig::*;
+
+pub mod queued_admin_action;
+pub use queued_admin_action::*;
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constant::{ANCHOR_DISCRIMINATOR_SIZE, MAX_MULTISIG_SIGNERS},
+    error::CustomError,
+};
+
+/// M-of-N signer set that acts through its `multisig_authority` PDA.
+#[account]
+#[derive(InitSpace)]
+pub struct Multisig {
+    pub vesting_account: Pubkey,
+    #[max_len(MAX_MULTISIG_SIGNERS)]
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub proposal_count: u64,
+    /// Proposals that have not been executed or cancelled yet.
+    pub open_proposal_count: u64,
+    /// Bumped whenever the signer set changes, invalidating approvals gathered before.
+    pub config_version: u32,
+    pub authority_bump: u8,
+    pub bump: u8,
+}
+
+impl Multisig {
+    pub fn validate_config(signers: &[Pubkey], threshold: u8) -> Result<()> {
+        require!(
+            !signers.is_empty()
+                && signers.len() <= MAX_MULTISIG_SIGNERS
+                && threshold > 0
+                && usize::from(threshold) <= signers.len(),
+            CustomError::InvalidMultisigConfig
+        );
+        for (index, signer) in signers.iter().enumerate() {
+            require!(
+                !signers[..index].contains(signer),
+                CustomError::InvalidMultisigConfig
+            );
+        }
+        Ok(())
+    }
+
+    pub fn signer_index(&self, signer: &Pubkey) -> Option<usize> {
+        self.signers.iter().position(|key| key == signer)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace, Debug)]
+pub struct ProposalAccountMeta {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+/// A single instruction to this program, executed with the multisig authority as signer.
+#[account]
+#[derive(InitSpace)]
+pub struct MultisigProposal {
+    pub multisig: Pubkey,
+    pub index: u64,
+    pub proposer: Pubkey,
+    /// `Multisig::config_version` the approvals were collected under.
+    pub config_version: u32,
+    /// Bit `i` is set once `multisig.signers[i]` has approved.
+    pub approvals: u16,
+    pub executed: bool,
+    pub bump: u8,
+    #[max_len(0)]
+    pub instruction_accounts: Vec<ProposalAccountMeta>,
+    #[max_len(0)]
+    pub instruction_data: Vec<u8>,
+}
+
+impl MultisigProposal {
+    /// Account space needed for a proposal carrying the given instruction.
+    pub fn space(account_count: usize, data_len: usize) -> usize {
+        ANCHOR_DISCRIMINATOR_SIZE
+            + MultisigProposal::INIT_SPACE
+            + account_count * ProposalAccountMeta::INIT_SPACE
+            + data_len
+    }
+
+    pub fn is_approved_by(&self, signer_index: usize) -> bool {
+        self.approvals & (1 << signer_index) != 0
+    }
+
+    pub fn approve(&mut self, signer_index: usize) {
+        self.approvals |= 1 << signer_index;
+    }
+
+    pub fn approval_count(&self) -> u32 {
+        self.approvals.count_ones()
+    }
+}
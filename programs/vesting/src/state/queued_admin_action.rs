@@ -0,0 +1,80 @@
+use anchor_lang::prelude::{borsh, *};
+
+use crate::state::VestingAccount;
+
+/// Sensitive admin actions that must wait out `VestingAccount::admin_action_delay`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace, Debug)]
+pub enum AdminAction {
+    ChangeAdmin {
+        new_admin: Pubkey,
+    },
+    Revoke {
+        beneficiary_vesting_account: Pubkey,
+    },
+    WithdrawSurplus {
+        destination_token_account: Pubkey,
+    },
+    SetAdminActionDelay {
+        delay: i64,
+    },
+    AmendSchedule {
+        beneficiary_vesting_account: Pubkey,
+        total_amount: u64,
+        end_time: i64,
+        cliff_time: i64,
+    },
+    Cancel {
+        beneficiary_vesting_account: Pubkey,
+    },
+    Suspend {
+        beneficiary_vesting_account: Pubkey,
+    },
+}
+
+impl AdminAction {
+    /// The role allowed to queue and execute this action.
+    pub fn authority(&self, vesting_account: &VestingAccount) -> Pubkey {
+        match self {
+            AdminAction::ChangeAdmin { .. }
+            | AdminAction::SetAdminActionDelay { .. }
+            | AdminAction::Cancel { .. } => vesting_account.admin,
+            AdminAction::Revoke { .. } | AdminAction::Suspend { .. } => vesting_account.revoker,
+            AdminAction::WithdrawSurplus { .. } => vesting_account.treasurer,
+            AdminAction::AmendSchedule { .. } => vesting_account.scheduler,
+        }
+    }
+
+    /// The grant this action targets, which must stay in place while it is queued.
+    pub fn beneficiary_vesting_account(&self) -> Option<Pubkey> {
+        match self {
+            AdminAction::Revoke {
+                beneficiary_vesting_account,
+            }
+            | AdminAction::AmendSchedule {
+                beneficiary_vesting_account,
+                ..
+            }
+            | AdminAction::Cancel {
+                beneficiary_vesting_account,
+            }
+            | AdminAction::Suspend {
+                beneficiary_vesting_account,
+            } => Some(*beneficiary_vesting_account),
+            AdminAction::ChangeAdmin { .. }
+            | AdminAction::WithdrawSurplus { .. }
+            | AdminAction::SetAdminActionDelay { .. } => None,
+        }
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct QueuedAdminAction {
+    pub vesting_account: Pubkey,
+    pub index: u64,
+    pub action: AdminAction,
+    pub queued_by: Pubkey,
+    /// Runs once the vesting account's current `admin_action_delay` has passed since then.
+    pub queued_at: i64,
+    pub bump: u8,
+}
@@ -1,15 +1,72 @@
 use anchor_lang::prelude::*;
 
+use crate::error::CustomError;
+
 #[account]
 #[derive(InitSpace)]
 pub struct VestingAccount {
     pub id: u64,
     pub admin: Pubkey,
+    /// Proposed admin, who must accept before taking over.
+    pub pending_admin: Option<Pubkey>,
+    /// Can create vesting schedules.
+    pub scheduler: Pubkey,
+    /// Can revoke vesting schedules.
+    pub revoker: Pubkey,
+    /// Can move surplus tokens out of the treasury.
+    pub treasurer: Pubkey,
     pub mint: Pubkey,
     pub treasury_token_account: Pubkey,
     #[max_len(50)]
     pub company_name: String,
     pub total_token_obligation: u64,
+    /// Beneficiary accounts that still exist for this vesting account.
+    pub beneficiary_count: u64,
+    /// Blocks claims and every other treasury outflow while set.
+    pub paused: bool,
+    /// Beneficiaries need the admin to co-sign moving a grant to a new wallet.
+    pub grant_transfer_requires_approval: bool,
+    /// Seconds sensitive admin actions must stay queued before they run; 0 runs them immediately.
+    pub admin_action_delay: i64,
+    pub queued_admin_action_count: u64,
+    /// Queued admin actions that have not been executed or cancelled yet.
+    pub open_admin_action_count: u64,
     pub treasury_bump: u8,
     pub bump: u8,
 }
+
+impl VestingAccount {
+    /// Drops `amount` from the outstanding obligation once it is no longer owed.
+    pub fn release_obligation(&mut self, amount: u64) -> Result<()> {
+        self.total_token_obligation = self
+            .total_token_obligation
+            .checked_sub(amount)
+            .ok_or(CustomError::MathOverflow)?;
+        Ok(())
+    }
+
+    pub fn add_beneficiary_account(&mut self) -> Result<()> {
+        self.beneficiary_count = self
+            .beneficiary_count
+            .checked_add(1)
+            .ok_or(CustomError::MathOverflow)?;
+        Ok(())
+    }
+
+    pub fn remove_beneficiary_account(&mut self) -> Result<()> {
+        self.beneficiary_count = self
+            .beneficiary_count
+            .checked_sub(1)
+            .ok_or(CustomError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Fails unless the treasury balance still covers every unclaimed entitlement.
+    pub fn assert_treasury_covers_obligation(&self, treasury_amount: u64) -> Result<()> {
+        require!(
+            treasury_amount >= self.total_token_obligation,
+            CustomError::NotEnoughTokensInTreasury
+        );
+        Ok(())
+    }
+}
@@ -1,19 +1,42 @@
-use anchor_lang::AccountDeserialize;
+use anchor_lang::{prelude::Clock, system_program, AccountDeserialize};
+use anchor_spl::associated_token::get_associated_token_address;
 // use anchor_lang::Key;
-use solana_kite::{get_pda_and_bump, seeds, send_transaction_from_instructions};
+use solana_kite::{
+    assert_token_balance, create_associated_token_account, create_wallet, get_pda_and_bump, seeds,
+    send_transaction_from_instructions,
+};
 use solana_message::Message;
 use solana_signer::Signer;
 use solana_transaction::Transaction;
 
 use crate::{
-    state::VestingAccount,
+    error::CustomError,
+    instructions::{AmendVestingScheduleParams, VestingScheduleParams},
+    state::{
+        AdminAction, BeneficiaryAccount, Multisig, ScheduleKind, UnlockCheckpoint, VestingAccount,
+    },
     vesting_test_helper::{
+        build_accept_admin_instruction, build_amend_vesting_schedule_instruction,
+        build_approve_multisig_proposal_instruction, build_beneficiary_account,
+        build_cancel_admin_action_instruction, build_claim_vested_tokens_instruction,
+        build_close_vesting_account_instruction, build_create_multisig_instruction,
+        build_create_multisig_proposal_instruction, build_execute_multisig_proposal_instruction,
         build_initialize_vesting_accounts, build_initialize_vesting_instruction,
-        generate_vesting_account_id, setup_vesting_test,
+        build_initialize_vesting_schedule_instruction, build_propose_admin_instruction,
+        build_queue_admin_action_instruction, build_resume_beneficiary_instruction,
+        build_revoke_beneficiary_instruction, build_set_admin_action_delay_instruction,
+        build_suspend_beneficiary_instruction, build_transfer_grant_instruction,
+        build_update_multisig_instruction, create_funded_vesting_account, create_linear_grant,
+        generate_vesting_account_id, get_beneficiary_vesting_account_address, get_multisig_address,
+        get_multisig_authority_address, get_multisig_proposal_address,
+        get_queued_admin_action_address, is_program_error, setup_vesting_test,
+        AmendVestingScheduleAccounts, InitializeVestingScheduleAccounts, RevokeBeneficiaryAccounts,
+        LAMPORTS_PER_SOL, SECONDS_PER_YEAR,
     },
 };
 
 #[test]
+#[allow(clippy::useless_vec)]
 pub fn test_initialize_vesting() {
     let mut test_environment = setup_vesting_test();
 
@@ -80,3 +103,1308 @@ pub fn test_initialize_vesting() {
     assert_eq!(vesting_data.company_name, company_name);
     assert_eq!(vesting_data.id, vesting_id);
 }
+
+#[test]
+pub fn test_revoke_by_stranger_is_rejected() {
+    let mut test_environment = setup_vesting_test();
+    let program_id = test_environment.program_id;
+    let employer = test_environment.employer.pubkey();
+    let mint = test_environment.token_mint;
+    let beneficiary = test_environment._worker;
+
+    let funded_vesting_account =
+        create_funded_vesting_account(&mut test_environment, "company", 1_000);
+    let beneficiary_vesting_account = get_beneficiary_vesting_account_address(
+        &program_id,
+        &beneficiary,
+        &funded_vesting_account.vesting_account,
+        0,
+    );
+
+    let initialize_schedule_instruction = build_initialize_vesting_schedule_instruction(
+        0,
+        VestingScheduleParams {
+            start_time: 0,
+            end_time: 1_000,
+            total_amount: 1_000,
+            cliff_time: 100,
+            cliff_amount: 0,
+            initial_unlock_amount: 0,
+            schedule_kind: ScheduleKind::Linear,
+            unlock_checkpoints: vec![],
+        },
+        InitializeVestingScheduleAccounts {
+            scheduler: employer,
+            vesting_account: funded_vesting_account.vesting_account,
+            mint,
+            beneficiary,
+            beneficiary_vesting_account,
+            treasury_token_account: funded_vesting_account.treasury_token_account,
+            system_program: system_program::ID,
+        },
+    );
+    send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![initialize_schedule_instruction],
+        &[&test_environment.employer],
+        &employer,
+    )
+    .unwrap();
+
+    let stranger = create_wallet(&mut test_environment.litesvm, LAMPORTS_PER_SOL).unwrap();
+    let stranger_ata =
+        create_associated_token_account(&mut test_environment.litesvm, &stranger, &mint, &stranger)
+            .unwrap();
+
+    let revoke_instruction = build_revoke_beneficiary_instruction(RevokeBeneficiaryAccounts {
+        revoker: stranger.pubkey(),
+        beneficiary,
+        vesting_account: funded_vesting_account.vesting_account,
+        mint,
+        treasury_token_account: funded_vesting_account.treasury_token_account,
+        refund_token_account: stranger_ata,
+        beneficiary_vesting_account,
+        queued_admin_action: None,
+        token_program: spl_token::ID,
+    });
+    let result = send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![revoke_instruction],
+        &[&stranger],
+        &stranger.pubkey(),
+    );
+
+    assert!(
+        is_program_error(&result, CustomError::UnAuthorized),
+        "Revoke by a stranger should be rejected"
+    );
+
+    let beneficiary_data = test_environment
+        .litesvm
+        .get_account(&beneficiary_vesting_account)
+        .expect("Beneficiary account should exist")
+        .data;
+    let beneficiary_account =
+        BeneficiaryAccount::try_deserialize(&mut beneficiary_data.as_slice()).unwrap();
+    assert_eq!(beneficiary_account.revoke_at, None);
+    assert_token_balance(
+        &test_environment.litesvm,
+        &funded_vesting_account.treasury_token_account,
+        1_000,
+        "Treasury should keep the unvested tokens",
+    );
+}
+
+#[test]
+pub fn test_revoke_waits_for_admin_action_delay() {
+    let mut test_environment = setup_vesting_test();
+    let program_id = test_environment.program_id;
+    let employer = test_environment.employer.pubkey();
+    let mint = test_environment.token_mint;
+    let beneficiary = test_environment._worker;
+
+    let funded_vesting_account =
+        create_funded_vesting_account(&mut test_environment, "company", 1_000);
+    let vesting_account = funded_vesting_account.vesting_account;
+    let beneficiary_vesting_account = create_linear_grant(
+        &mut test_environment,
+        &funded_vesting_account,
+        beneficiary,
+        1_000,
+    );
+
+    send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![build_set_admin_action_delay_instruction(
+            employer,
+            vesting_account,
+            100,
+            None,
+        )],
+        &[&test_environment.employer],
+        &employer,
+    )
+    .unwrap();
+
+    let queued_admin_action = get_queued_admin_action_address(&program_id, &vesting_account, 0);
+    let revoke_accounts = |queued_admin_action| RevokeBeneficiaryAccounts {
+        revoker: employer,
+        beneficiary,
+        vesting_account,
+        mint,
+        treasury_token_account: funded_vesting_account.treasury_token_account,
+        refund_token_account: get_associated_token_address(&employer, &mint),
+        beneficiary_vesting_account,
+        queued_admin_action,
+        token_program: spl_token::ID,
+    };
+
+    let result = send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![build_revoke_beneficiary_instruction(revoke_accounts(None))],
+        &[&test_environment.employer],
+        &employer,
+    );
+    assert!(
+        is_program_error(&result, CustomError::AdminActionNotQueued),
+        "Revoke should need a queued action once a delay is set"
+    );
+
+    send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![build_queue_admin_action_instruction(
+            employer,
+            vesting_account,
+            0,
+            AdminAction::Revoke {
+                beneficiary_vesting_account,
+            },
+        )],
+        &[&test_environment.employer],
+        &employer,
+    )
+    .unwrap();
+
+    let revoke_instruction =
+        build_revoke_beneficiary_instruction(revoke_accounts(Some(queued_admin_action)));
+    let result = send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![revoke_instruction.clone()],
+        &[&test_environment.employer],
+        &employer,
+    );
+    assert!(
+        is_program_error(&result, CustomError::AdminActionNotReady),
+        "Revoke should wait out the delay"
+    );
+
+    let mut clock = test_environment.litesvm.get_sysvar::<Clock>();
+    clock.unix_timestamp += 100;
+    test_environment.litesvm.set_sysvar(&clock);
+    test_environment.litesvm.expire_blockhash();
+
+    send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![revoke_instruction],
+        &[&test_environment.employer],
+        &employer,
+    )
+    .unwrap();
+
+    let beneficiary_data = test_environment
+        .litesvm
+        .get_account(&beneficiary_vesting_account)
+        .expect("Beneficiary account should exist")
+        .data;
+    let beneficiary_account =
+        BeneficiaryAccount::try_deserialize(&mut beneficiary_data.as_slice()).unwrap();
+    assert_eq!(beneficiary_account.revoke_at, Some(clock.unix_timestamp));
+    assert!(
+        test_environment
+            .litesvm
+            .get_account(&queued_admin_action)
+            .is_none_or(|account| account.lamports == 0),
+        "Queued action should be closed once executed"
+    );
+}
+
+#[test]
+pub fn test_actions_queued_before_a_delay_increase_wait_out_the_new_delay() {
+    let mut test_environment = setup_vesting_test();
+    let program_id = test_environment.program_id;
+    let employer = test_environment.employer.pubkey();
+    let mint = test_environment.token_mint;
+    let beneficiary = test_environment._worker;
+
+    let funded_vesting_account =
+        create_funded_vesting_account(&mut test_environment, "company", 1_000);
+    let vesting_account = funded_vesting_account.vesting_account;
+    let beneficiary_vesting_account = create_linear_grant(
+        &mut test_environment,
+        &funded_vesting_account,
+        beneficiary,
+        1_000,
+    );
+
+    // Queued while there is no delay, then the delay is switched on.
+    send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![
+            build_queue_admin_action_instruction(
+                employer,
+                vesting_account,
+                0,
+                AdminAction::Revoke {
+                    beneficiary_vesting_account,
+                },
+            ),
+            build_set_admin_action_delay_instruction(employer, vesting_account, 100, None),
+        ],
+        &[&test_environment.employer],
+        &employer,
+    )
+    .unwrap();
+
+    let revoke_instruction = build_revoke_beneficiary_instruction(RevokeBeneficiaryAccounts {
+        revoker: employer,
+        beneficiary,
+        vesting_account,
+        mint,
+        treasury_token_account: funded_vesting_account.treasury_token_account,
+        refund_token_account: get_associated_token_address(&employer, &mint),
+        beneficiary_vesting_account,
+        queued_admin_action: Some(get_queued_admin_action_address(
+            &program_id,
+            &vesting_account,
+            0,
+        )),
+        token_program: spl_token::ID,
+    });
+    let result = send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![revoke_instruction.clone()],
+        &[&test_environment.employer],
+        &employer,
+    );
+    assert!(
+        is_program_error(&result, CustomError::AdminActionNotReady),
+        "An action queued before the delay was raised should still wait out the new delay"
+    );
+
+    let mut clock = test_environment.litesvm.get_sysvar::<Clock>();
+    clock.unix_timestamp += 100;
+    test_environment.litesvm.set_sysvar(&clock);
+    test_environment.litesvm.expire_blockhash();
+
+    send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![revoke_instruction],
+        &[&test_environment.employer],
+        &employer,
+    )
+    .unwrap();
+}
+
+#[test]
+pub fn test_amend_reduction_waits_for_admin_action_delay() {
+    let mut test_environment = setup_vesting_test();
+    let program_id = test_environment.program_id;
+    let employer = test_environment.employer.pubkey();
+    let beneficiary = test_environment._worker;
+
+    let funded_vesting_account =
+        create_funded_vesting_account(&mut test_environment, "company", 1_000);
+    let vesting_account = funded_vesting_account.vesting_account;
+    let beneficiary_vesting_account = create_linear_grant(
+        &mut test_environment,
+        &funded_vesting_account,
+        beneficiary,
+        1_000,
+    );
+
+    send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![build_set_admin_action_delay_instruction(
+            employer,
+            vesting_account,
+            50,
+            None,
+        )],
+        &[&test_environment.employer],
+        &employer,
+    )
+    .unwrap();
+
+    let queued_admin_action = get_queued_admin_action_address(&program_id, &vesting_account, 0);
+    let amend_instruction = |queued_admin_action| {
+        build_amend_vesting_schedule_instruction(
+            AmendVestingScheduleParams {
+                total_amount: Some(500),
+                end_time: None,
+                cliff_time: None,
+            },
+            AmendVestingScheduleAccounts {
+                scheduler: employer,
+                beneficiary,
+                vesting_account,
+                treasury_token_account: funded_vesting_account.treasury_token_account,
+                beneficiary_vesting_account,
+                queued_admin_action,
+            },
+        )
+    };
+
+    let result = send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![amend_instruction(None)],
+        &[&test_environment.employer],
+        &employer,
+    );
+    assert!(
+        is_program_error(&result, CustomError::AdminActionNotQueued),
+        "Lowering the total should need a queued action once a delay is set"
+    );
+
+    send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![build_queue_admin_action_instruction(
+            employer,
+            vesting_account,
+            0,
+            AdminAction::AmendSchedule {
+                beneficiary_vesting_account,
+                total_amount: 500,
+                end_time: 1_000,
+                cliff_time: 100,
+            },
+        )],
+        &[&test_environment.employer],
+        &employer,
+    )
+    .unwrap();
+
+    let mut clock = test_environment.litesvm.get_sysvar::<Clock>();
+    clock.unix_timestamp += 50;
+    test_environment.litesvm.set_sysvar(&clock);
+    test_environment.litesvm.expire_blockhash();
+
+    send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![amend_instruction(Some(queued_admin_action))],
+        &[&test_environment.employer],
+        &employer,
+    )
+    .unwrap();
+
+    let beneficiary_data = test_environment
+        .litesvm
+        .get_account(&beneficiary_vesting_account)
+        .expect("Beneficiary account should exist")
+        .data;
+    let beneficiary_account =
+        BeneficiaryAccount::try_deserialize(&mut beneficiary_data.as_slice()).unwrap();
+    assert_eq!(beneficiary_account.total_amount, 500);
+}
+
+#[test]
+pub fn test_suspension_pauses_accrual_and_resume_extends_end_time() {
+    let mut test_environment = setup_vesting_test();
+    let employer = test_environment.employer.pubkey();
+    let mint = test_environment.token_mint;
+
+    let funded_vesting_account =
+        create_funded_vesting_account(&mut test_environment, "company", 1_000);
+    let vesting_account = funded_vesting_account.vesting_account;
+    let beneficiary = create_wallet(&mut test_environment.litesvm, LAMPORTS_PER_SOL).unwrap();
+    let beneficiary_vesting_account = create_linear_grant(
+        &mut test_environment,
+        &funded_vesting_account,
+        beneficiary.pubkey(),
+        1_000,
+    );
+    let stranger = create_wallet(&mut test_environment.litesvm, LAMPORTS_PER_SOL).unwrap();
+
+    let suspend_instruction = |revoker| {
+        build_suspend_beneficiary_instruction(
+            revoker,
+            beneficiary.pubkey(),
+            vesting_account,
+            beneficiary_vesting_account,
+            None,
+        )
+    };
+    let resume_instruction = |revoker| {
+        build_resume_beneficiary_instruction(
+            revoker,
+            beneficiary.pubkey(),
+            vesting_account,
+            beneficiary_vesting_account,
+        )
+    };
+    let claim_instruction = build_claim_vested_tokens_instruction(
+        beneficiary.pubkey(),
+        mint,
+        vesting_account,
+        funded_vesting_account.treasury_token_account,
+        beneficiary_vesting_account,
+    );
+
+    let mut clock = test_environment.litesvm.get_sysvar::<Clock>();
+    clock.unix_timestamp = 200;
+    test_environment.litesvm.set_sysvar(&clock);
+
+    let result = send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![suspend_instruction(stranger.pubkey())],
+        &[&stranger],
+        &stranger.pubkey(),
+    );
+    assert!(
+        is_program_error(&result, CustomError::UnAuthorized),
+        "Only the revoker should be able to suspend"
+    );
+
+    send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![suspend_instruction(employer), claim_instruction.clone()],
+        &[&test_environment.employer, &beneficiary],
+        &employer,
+    )
+    .unwrap();
+    let beneficiary_ata = get_associated_token_address(&beneficiary.pubkey(), &mint);
+    assert_token_balance(
+        &test_environment.litesvm,
+        &beneficiary_ata,
+        200,
+        "Tokens vested before the suspension should stay claimable",
+    );
+
+    clock.unix_timestamp = 500;
+    test_environment.litesvm.set_sysvar(&clock);
+    test_environment.litesvm.expire_blockhash();
+
+    let result = send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![claim_instruction],
+        &[&beneficiary],
+        &beneficiary.pubkey(),
+    );
+    assert!(
+        is_program_error(&result, CustomError::NothingToClaim),
+        "Nothing new should vest while suspended"
+    );
+
+    let result = send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![resume_instruction(stranger.pubkey())],
+        &[&stranger],
+        &stranger.pubkey(),
+    );
+    assert!(
+        is_program_error(&result, CustomError::UnAuthorized),
+        "Only the revoker should be able to resume"
+    );
+
+    send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![resume_instruction(employer)],
+        &[&test_environment.employer],
+        &employer,
+    )
+    .unwrap();
+
+    let beneficiary_data = test_environment
+        .litesvm
+        .get_account(&beneficiary_vesting_account)
+        .expect("Beneficiary account should exist")
+        .data;
+    let beneficiary_account =
+        BeneficiaryAccount::try_deserialize(&mut beneficiary_data.as_slice()).unwrap();
+    assert_eq!(beneficiary_account.suspended_at, None);
+    assert_eq!(beneficiary_account.suspended_seconds, 300);
+    assert_eq!(beneficiary_account.end_time, 1_300);
+}
+
+#[test]
+pub fn test_suspend_waits_for_admin_action_delay() {
+    let mut test_environment = setup_vesting_test();
+    let program_id = test_environment.program_id;
+    let employer = test_environment.employer.pubkey();
+    let beneficiary = test_environment._worker;
+
+    let funded_vesting_account =
+        create_funded_vesting_account(&mut test_environment, "company", 1_000);
+    let vesting_account = funded_vesting_account.vesting_account;
+    let beneficiary_vesting_account = create_linear_grant(
+        &mut test_environment,
+        &funded_vesting_account,
+        beneficiary,
+        1_000,
+    );
+    let queued_admin_action = get_queued_admin_action_address(&program_id, &vesting_account, 0);
+    let suspend_instruction = |queued_admin_action| {
+        build_suspend_beneficiary_instruction(
+            employer,
+            beneficiary,
+            vesting_account,
+            beneficiary_vesting_account,
+            queued_admin_action,
+        )
+    };
+
+    send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![build_set_admin_action_delay_instruction(
+            employer,
+            vesting_account,
+            100,
+            None,
+        )],
+        &[&test_environment.employer],
+        &employer,
+    )
+    .unwrap();
+
+    let result = send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![suspend_instruction(None)],
+        &[&test_environment.employer],
+        &employer,
+    );
+    assert!(
+        is_program_error(&result, CustomError::AdminActionNotQueued),
+        "Suspend should need a queued action once a delay is set"
+    );
+
+    send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![build_queue_admin_action_instruction(
+            employer,
+            vesting_account,
+            0,
+            AdminAction::Suspend {
+                beneficiary_vesting_account,
+            },
+        )],
+        &[&test_environment.employer],
+        &employer,
+    )
+    .unwrap();
+
+    let result = send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![suspend_instruction(Some(queued_admin_action))],
+        &[&test_environment.employer],
+        &employer,
+    );
+    assert!(
+        is_program_error(&result, CustomError::AdminActionNotReady),
+        "Suspend should wait out the delay"
+    );
+
+    let mut clock = test_environment.litesvm.get_sysvar::<Clock>();
+    clock.unix_timestamp += 100;
+    test_environment.litesvm.set_sysvar(&clock);
+    test_environment.litesvm.expire_blockhash();
+
+    send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![suspend_instruction(Some(queued_admin_action))],
+        &[&test_environment.employer],
+        &employer,
+    )
+    .unwrap();
+
+    let beneficiary_data = test_environment
+        .litesvm
+        .get_account(&beneficiary_vesting_account)
+        .expect("Beneficiary account should exist")
+        .data;
+    let beneficiary_account =
+        BeneficiaryAccount::try_deserialize(&mut beneficiary_data.as_slice()).unwrap();
+    assert_eq!(beneficiary_account.suspended_at, Some(clock.unix_timestamp));
+}
+
+#[test]
+pub fn test_grant_transfer_waits_for_queued_admin_actions() {
+    let mut test_environment = setup_vesting_test();
+    let program_id = test_environment.program_id;
+    let employer = test_environment.employer.pubkey();
+
+    let funded_vesting_account =
+        create_funded_vesting_account(&mut test_environment, "company", 1_000);
+    let vesting_account = funded_vesting_account.vesting_account;
+    let beneficiary = create_wallet(&mut test_environment.litesvm, LAMPORTS_PER_SOL).unwrap();
+    let beneficiary_vesting_account = create_linear_grant(
+        &mut test_environment,
+        &funded_vesting_account,
+        beneficiary.pubkey(),
+        1_000,
+    );
+    let new_beneficiary = create_wallet(&mut test_environment.litesvm, LAMPORTS_PER_SOL)
+        .unwrap()
+        .pubkey();
+    let queued_admin_action = get_queued_admin_action_address(&program_id, &vesting_account, 0);
+    let transfer_instruction = build_transfer_grant_instruction(
+        beneficiary.pubkey(),
+        new_beneficiary,
+        None,
+        vesting_account,
+        employer,
+        beneficiary_vesting_account,
+        0,
+    );
+
+    send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![
+            build_set_admin_action_delay_instruction(employer, vesting_account, 100, None),
+            build_queue_admin_action_instruction(
+                employer,
+                vesting_account,
+                0,
+                AdminAction::Revoke {
+                    beneficiary_vesting_account,
+                },
+            ),
+        ],
+        &[&test_environment.employer],
+        &employer,
+    )
+    .unwrap();
+
+    let result = send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![transfer_instruction.clone()],
+        &[&beneficiary],
+        &beneficiary.pubkey(),
+    );
+    assert!(
+        is_program_error(&result, CustomError::GrantHasQueuedAdminActions),
+        "A grant with a queued revocation should not be able to move"
+    );
+
+    send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![build_cancel_admin_action_instruction(
+            employer,
+            vesting_account,
+            employer,
+            queued_admin_action,
+            Some(beneficiary_vesting_account),
+        )],
+        &[&test_environment.employer],
+        &employer,
+    )
+    .unwrap();
+    test_environment.litesvm.expire_blockhash();
+
+    send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![transfer_instruction],
+        &[&beneficiary],
+        &beneficiary.pubkey(),
+    )
+    .unwrap();
+    let new_beneficiary_vesting_account =
+        get_beneficiary_vesting_account_address(&program_id, &new_beneficiary, &vesting_account, 0);
+    assert!(
+        test_environment
+            .litesvm
+            .get_account(&new_beneficiary_vesting_account)
+            .is_some_and(|account| account.lamports > 0),
+        "Grant should move once the queued action is cancelled"
+    );
+}
+
+#[test]
+pub fn test_grant_transfer_rejects_a_wallet_holding_the_same_grant_id() {
+    let mut test_environment = setup_vesting_test();
+    let employer = test_environment.employer.pubkey();
+
+    let funded_vesting_account =
+        create_funded_vesting_account(&mut test_environment, "company", 2_000);
+    let vesting_account = funded_vesting_account.vesting_account;
+    let beneficiary = create_wallet(&mut test_environment.litesvm, LAMPORTS_PER_SOL).unwrap();
+    let beneficiary_vesting_account = create_linear_grant(
+        &mut test_environment,
+        &funded_vesting_account,
+        beneficiary.pubkey(),
+        1_000,
+    );
+    let new_beneficiary = create_wallet(&mut test_environment.litesvm, LAMPORTS_PER_SOL)
+        .unwrap()
+        .pubkey();
+    create_linear_grant(
+        &mut test_environment,
+        &funded_vesting_account,
+        new_beneficiary,
+        1_000,
+    );
+
+    let result = send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![build_transfer_grant_instruction(
+            beneficiary.pubkey(),
+            new_beneficiary,
+            None,
+            vesting_account,
+            employer,
+            beneficiary_vesting_account,
+            0,
+        )],
+        &[&beneficiary],
+        &beneficiary.pubkey(),
+    );
+    assert!(
+        is_program_error(&result, CustomError::GrantAlreadyExists),
+        "A grant should not move onto a wallet that already holds the same grant id"
+    );
+}
+
+#[test]
+pub fn test_multisig_executes_admin_handover_once_threshold_is_met() {
+    let mut test_environment = setup_vesting_test();
+    let program_id = test_environment.program_id;
+    let employer = test_environment.employer.pubkey();
+
+    let funded_vesting_account =
+        create_funded_vesting_account(&mut test_environment, "company", 1_000);
+    let vesting_account = funded_vesting_account.vesting_account;
+    let multisig = get_multisig_address(&program_id, &vesting_account);
+    let multisig_authority = get_multisig_authority_address(&program_id, &multisig);
+
+    let alice = create_wallet(&mut test_environment.litesvm, LAMPORTS_PER_SOL).unwrap();
+    let bob = create_wallet(&mut test_environment.litesvm, LAMPORTS_PER_SOL).unwrap();
+
+    send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![
+            build_create_multisig_instruction(
+                employer,
+                vesting_account,
+                vec![employer, alice.pubkey(), bob.pubkey()],
+                2,
+            ),
+            build_propose_admin_instruction(employer, vesting_account, multisig_authority, None),
+        ],
+        &[&test_environment.employer],
+        &employer,
+    )
+    .unwrap();
+
+    let accept_admin_instruction =
+        build_accept_admin_instruction(multisig_authority, vesting_account);
+    let proposal = get_multisig_proposal_address(&program_id, &multisig, 0);
+    send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![build_create_multisig_proposal_instruction(
+            alice.pubkey(),
+            multisig,
+            0,
+            &accept_admin_instruction,
+        )],
+        &[&alice],
+        &alice.pubkey(),
+    )
+    .unwrap();
+
+    let execute_instruction = build_execute_multisig_proposal_instruction(
+        alice.pubkey(),
+        multisig,
+        proposal,
+        alice.pubkey(),
+        &accept_admin_instruction,
+    );
+    let result = send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![execute_instruction.clone()],
+        &[&alice],
+        &alice.pubkey(),
+    );
+    assert!(
+        is_program_error(&result, CustomError::NotEnoughApprovals),
+        "A single approval should not meet the threshold"
+    );
+
+    send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![build_approve_multisig_proposal_instruction(
+            bob.pubkey(),
+            multisig,
+            proposal,
+        )],
+        &[&bob],
+        &bob.pubkey(),
+    )
+    .unwrap();
+
+    test_environment.litesvm.expire_blockhash();
+    send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![execute_instruction.clone()],
+        &[&alice],
+        &alice.pubkey(),
+    )
+    .unwrap();
+
+    let vesting_account_data = test_environment
+        .litesvm
+        .get_account(&vesting_account)
+        .expect("Vesting account should exist")
+        .data;
+    let vesting_data =
+        VestingAccount::try_deserialize(&mut vesting_account_data.as_slice()).unwrap();
+    assert_eq!(vesting_data.admin, multisig_authority);
+    assert_eq!(vesting_data.pending_admin, None);
+    assert!(
+        test_environment
+            .litesvm
+            .get_account(&proposal)
+            .is_none_or(|account| account.lamports == 0),
+        "Proposal should be closed once executed"
+    );
+
+    test_environment.litesvm.expire_blockhash();
+    let result = send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![execute_instruction],
+        &[&alice],
+        &alice.pubkey(),
+    );
+    assert!(result.is_err(), "A proposal should only execute once");
+}
+
+#[test]
+pub fn test_multisig_can_replace_its_signers_through_a_proposal() {
+    let mut test_environment = setup_vesting_test();
+    let program_id = test_environment.program_id;
+    let employer = test_environment.employer.pubkey();
+
+    let funded_vesting_account =
+        create_funded_vesting_account(&mut test_environment, "company", 1_000);
+    let vesting_account = funded_vesting_account.vesting_account;
+    let multisig = get_multisig_address(&program_id, &vesting_account);
+
+    let alice = create_wallet(&mut test_environment.litesvm, LAMPORTS_PER_SOL).unwrap();
+    let bob = create_wallet(&mut test_environment.litesvm, LAMPORTS_PER_SOL).unwrap();
+    let carol = create_wallet(&mut test_environment.litesvm, LAMPORTS_PER_SOL).unwrap();
+
+    let update_multisig_instruction = build_update_multisig_instruction(
+        multisig,
+        vec![alice.pubkey(), bob.pubkey(), carol.pubkey()],
+        2,
+    );
+    let update_proposal = get_multisig_proposal_address(&program_id, &multisig, 0);
+    let pending_proposal = get_multisig_proposal_address(&program_id, &multisig, 1);
+    send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![
+            build_create_multisig_instruction(
+                employer,
+                vesting_account,
+                vec![employer, alice.pubkey()],
+                2,
+            ),
+            build_create_multisig_proposal_instruction(
+                employer,
+                multisig,
+                0,
+                &update_multisig_instruction,
+            ),
+            build_create_multisig_proposal_instruction(
+                employer,
+                multisig,
+                1,
+                &build_update_multisig_instruction(multisig, vec![employer], 1),
+            ),
+        ],
+        &[&test_environment.employer],
+        &employer,
+    )
+    .unwrap();
+
+    send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![
+            build_approve_multisig_proposal_instruction(alice.pubkey(), multisig, update_proposal),
+            build_execute_multisig_proposal_instruction(
+                alice.pubkey(),
+                multisig,
+                update_proposal,
+                employer,
+                &update_multisig_instruction,
+            ),
+        ],
+        &[&alice],
+        &alice.pubkey(),
+    )
+    .unwrap();
+
+    let multisig_data = test_environment
+        .litesvm
+        .get_account(&multisig)
+        .expect("Multisig should exist")
+        .data;
+    let multisig_account = Multisig::try_deserialize(&mut multisig_data.as_slice()).unwrap();
+    assert_eq!(
+        multisig_account.signers,
+        vec![alice.pubkey(), bob.pubkey(), carol.pubkey()]
+    );
+    assert_eq!(multisig_account.threshold, 2);
+    assert_eq!(multisig_account.config_version, 1);
+    assert!(
+        test_environment
+            .litesvm
+            .get_account(&update_proposal)
+            .is_none_or(|account| account.lamports == 0),
+        "Proposal should be closed once executed"
+    );
+
+    let result = send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![build_approve_multisig_proposal_instruction(
+            bob.pubkey(),
+            multisig,
+            pending_proposal,
+        )],
+        &[&bob],
+        &bob.pubkey(),
+    );
+    assert!(
+        is_program_error(&result, CustomError::StaleMultisigProposal),
+        "Approvals gathered under the old signers should not carry over"
+    );
+}
+
+#[test]
+pub fn test_close_vesting_account_clears_its_multisig_and_queued_actions() {
+    let mut test_environment = setup_vesting_test();
+    let program_id = test_environment.program_id;
+    let employer = test_environment.employer.pubkey();
+    let mint = test_environment.token_mint;
+
+    let funded_vesting_account =
+        create_funded_vesting_account(&mut test_environment, "company", 1_000);
+    let vesting_account = funded_vesting_account.vesting_account;
+    let treasury_token_account = funded_vesting_account.treasury_token_account;
+    let multisig = get_multisig_address(&program_id, &vesting_account);
+    let queued_admin_action = get_queued_admin_action_address(&program_id, &vesting_account, 0);
+    let vesting_account_data = test_environment
+        .litesvm
+        .get_account(&vesting_account)
+        .expect("Vesting account should exist")
+        .data;
+    let vesting_id = VestingAccount::try_deserialize(&mut vesting_account_data.as_slice())
+        .unwrap()
+        .id;
+
+    send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![
+            build_create_multisig_instruction(employer, vesting_account, vec![employer], 1),
+            build_queue_admin_action_instruction(
+                employer,
+                vesting_account,
+                0,
+                AdminAction::SetAdminActionDelay { delay: 100 },
+            ),
+        ],
+        &[&test_environment.employer],
+        &employer,
+    )
+    .unwrap();
+
+    let close_instruction = build_close_vesting_account_instruction(
+        employer,
+        vesting_account,
+        mint,
+        treasury_token_account,
+    );
+    let result = send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![close_instruction.clone()],
+        &[&test_environment.employer],
+        &employer,
+    );
+    assert!(
+        is_program_error(&result, CustomError::VestingAccountInUse),
+        "A queued admin action should keep the vesting account open"
+    );
+
+    send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![
+            build_cancel_admin_action_instruction(
+                employer,
+                vesting_account,
+                employer,
+                queued_admin_action,
+                None,
+            ),
+            close_instruction,
+        ],
+        &[&test_environment.employer],
+        &employer,
+    )
+    .unwrap();
+    for closed_account in [vesting_account, treasury_token_account, multisig] {
+        assert!(
+            test_environment
+                .litesvm
+                .get_account(&closed_account)
+                .is_none_or(|account| account.lamports == 0),
+            "Vesting account, treasury and multisig should all be closed"
+        );
+    }
+
+    send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![
+            build_initialize_vesting_instruction(
+                String::from("company"),
+                vesting_id,
+                build_initialize_vesting_accounts(
+                    employer,
+                    mint,
+                    vesting_account,
+                    treasury_token_account,
+                ),
+            ),
+            build_create_multisig_instruction(employer, vesting_account, vec![employer], 1),
+            build_queue_admin_action_instruction(
+                employer,
+                vesting_account,
+                0,
+                AdminAction::SetAdminActionDelay { delay: 100 },
+            ),
+        ],
+        &[&test_environment.employer],
+        &employer,
+    )
+    .expect("A re-created vesting account should not collide with leftover accounts");
+}
+
+#[test]
+pub fn test_vested_amount_at_max_u64_supply() {
+    let start_time = 1_700_000_000;
+    let end_time = start_time + 10 * SECONDS_PER_YEAR;
+    let beneficiary_account = build_beneficiary_account(
+        u64::MAX,
+        start_time,
+        start_time + SECONDS_PER_YEAR,
+        end_time,
+    );
+
+    let midpoint = start_time + 5 * SECONDS_PER_YEAR;
+    assert_eq!(
+        beneficiary_account.vested_amount(midpoint).unwrap(),
+        u64::MAX / 2
+    );
+
+    let one_second_before_end = end_time - 1;
+    let expected = (u128::from(u64::MAX) * (one_second_before_end - start_time) as u128)
+        / (end_time - start_time) as u128;
+    assert_eq!(
+        beneficiary_account
+            .vested_amount(one_second_before_end)
+            .unwrap(),
+        expected as u64
+    );
+
+    assert_eq!(
+        beneficiary_account.vested_amount(end_time).unwrap(),
+        u64::MAX
+    );
+    assert_eq!(
+        beneficiary_account.vested_amount(i64::MAX).unwrap(),
+        u64::MAX
+    );
+}
+
+#[test]
+pub fn test_vested_amount_at_max_u64_supply_stops_at_revocation() {
+    let start_time = 0;
+    let end_time = 4 * SECONDS_PER_YEAR;
+    let mut beneficiary_account =
+        build_beneficiary_account(u64::MAX, start_time, SECONDS_PER_YEAR, end_time);
+    beneficiary_account.revoke_at = Some(2 * SECONDS_PER_YEAR);
+
+    assert_eq!(
+        beneficiary_account.vested_amount(end_time).unwrap(),
+        u64::MAX / 2
+    );
+    assert_eq!(
+        beneficiary_account
+            .vested_amount(SECONDS_PER_YEAR - 1)
+            .unwrap(),
+        0
+    );
+}
+
+#[test]
+pub fn test_periodic_vesting_rounds_down_to_completed_periods() {
+    let month = SECONDS_PER_YEAR / 12;
+    let mut beneficiary_account = build_beneficiary_account(1_200, 0, 3 * month, SECONDS_PER_YEAR);
+    beneficiary_account.schedule_kind = ScheduleKind::Periodic {
+        period_seconds: month,
+    };
+
+    assert_eq!(beneficiary_account.vested_amount(3 * month - 1).unwrap(), 0);
+    assert_eq!(beneficiary_account.vested_amount(3 * month).unwrap(), 300);
+    assert_eq!(
+        beneficiary_account.vested_amount(4 * month - 1).unwrap(),
+        300
+    );
+    assert_eq!(beneficiary_account.vested_amount(4 * month).unwrap(), 400);
+    assert_eq!(
+        beneficiary_account
+            .vested_amount(SECONDS_PER_YEAR - 1)
+            .unwrap(),
+        1_100
+    );
+    assert_eq!(
+        beneficiary_account.vested_amount(SECONDS_PER_YEAR).unwrap(),
+        1_200
+    );
+}
+
+#[test]
+pub fn test_cliff_amount_unlocks_at_cliff_then_vests_linearly() {
+    let mut beneficiary_account =
+        build_beneficiary_account(4_000, 0, SECONDS_PER_YEAR, 4 * SECONDS_PER_YEAR);
+    beneficiary_account.cliff_amount = 1_000;
+
+    assert_eq!(
+        beneficiary_account
+            .vested_amount(SECONDS_PER_YEAR - 1)
+            .unwrap(),
+        0
+    );
+    assert_eq!(
+        beneficiary_account.vested_amount(SECONDS_PER_YEAR).unwrap(),
+        1_000
+    );
+    assert_eq!(
+        beneficiary_account
+            .vested_amount(SECONDS_PER_YEAR + 3 * SECONDS_PER_YEAR / 2)
+            .unwrap(),
+        2_500
+    );
+    assert_eq!(
+        beneficiary_account
+            .vested_amount(4 * SECONDS_PER_YEAR)
+            .unwrap(),
+        4_000
+    );
+}
+
+#[test]
+pub fn test_initial_unlock_is_vested_from_start_time() {
+    let mut beneficiary_account = build_beneficiary_account(
+        1_000,
+        100,
+        100 + SECONDS_PER_YEAR,
+        100 + 2 * SECONDS_PER_YEAR,
+    );
+    beneficiary_account.initial_unlock_amount = 200;
+    beneficiary_account.cliff_amount = 300;
+
+    assert_eq!(beneficiary_account.vested_amount(99).unwrap(), 0);
+    assert_eq!(beneficiary_account.vested_amount(100).unwrap(), 200);
+    assert_eq!(
+        beneficiary_account
+            .vested_amount(100 + SECONDS_PER_YEAR)
+            .unwrap(),
+        500
+    );
+    assert_eq!(
+        beneficiary_account
+            .vested_amount(100 + 3 * SECONDS_PER_YEAR / 2)
+            .unwrap(),
+        750
+    );
+    assert_eq!(
+        beneficiary_account
+            .vested_amount(100 + 2 * SECONDS_PER_YEAR)
+            .unwrap(),
+        1_000
+    );
+}
+
+#[test]
+pub fn test_piecewise_vesting_steps_through_checkpoints() {
+    let month = SECONDS_PER_YEAR / 12;
+    let mut beneficiary_account = build_beneficiary_account(1_000, 0, 0, 36 * month);
+    beneficiary_account.schedule_kind = ScheduleKind::Piecewise;
+    beneficiary_account.unlock_checkpoints = vec![
+        UnlockCheckpoint {
+            timestamp: 6 * month,
+            cumulative_amount: 100,
+        },
+        UnlockCheckpoint {
+            timestamp: 7 * month,
+            cumulative_amount: 150,
+        },
+        UnlockCheckpoint {
+            timestamp: 36 * month,
+            cumulative_amount: 1_000,
+        },
+    ];
+
+    assert_eq!(beneficiary_account.vested_amount(6 * month - 1).unwrap(), 0);
+    assert_eq!(beneficiary_account.vested_amount(6 * month).unwrap(), 100);
+    assert_eq!(beneficiary_account.vested_amount(7 * month).unwrap(), 150);
+    assert_eq!(
+        beneficiary_account.vested_amount(36 * month - 1).unwrap(),
+        150
+    );
+    assert_eq!(
+        beneficiary_account.vested_amount(36 * month).unwrap(),
+        1_000
+    );
+
+    beneficiary_account.revoke_at = Some(6 * month + 1);
+    assert_eq!(beneficiary_account.vested_amount(36 * month).unwrap(), 100);
+}
+
+#[test]
+pub fn test_piecewise_checkpoints_before_the_cliff_unlock_at_the_cliff() {
+    let month = SECONDS_PER_YEAR / 12;
+    let mut beneficiary_account = build_beneficiary_account(1_000, 0, 12 * month, 36 * month);
+    beneficiary_account.schedule_kind = ScheduleKind::Piecewise;
+    beneficiary_account.unlock_checkpoints = vec![
+        UnlockCheckpoint {
+            timestamp: 6 * month,
+            cumulative_amount: 100,
+        },
+        UnlockCheckpoint {
+            timestamp: 24 * month,
+            cumulative_amount: 400,
+        },
+        UnlockCheckpoint {
+            timestamp: 36 * month,
+            cumulative_amount: 1_000,
+        },
+    ];
+
+    assert_eq!(beneficiary_account.vested_amount(6 * month).unwrap(), 0);
+    assert_eq!(
+        beneficiary_account.vested_amount(12 * month - 1).unwrap(),
+        0
+    );
+    assert_eq!(beneficiary_account.vested_amount(12 * month).unwrap(), 100);
+    assert_eq!(beneficiary_account.vested_amount(24 * month).unwrap(), 400);
+}
+
+#[test]
+pub fn test_suspension_pauses_accrual_and_pushes_back_end_time() {
+    let mut beneficiary_account = build_beneficiary_account(1_000, 0, 100, 1_000);
+    beneficiary_account.suspended_at = Some(400);
+
+    assert_eq!(beneficiary_account.vested_amount(400).unwrap(), 400);
+    assert_eq!(beneficiary_account.vested_amount(900).unwrap(), 400);
+
+    // Resumed at 900 after a 500 second leave, as `resume_beneficiary_account` records it.
+    beneficiary_account.suspended_at = None;
+    beneficiary_account.suspended_seconds = 500;
+    beneficiary_account.end_time = 1_500;
+
+    assert_eq!(beneficiary_account.vested_amount(900).unwrap(), 400);
+    assert_eq!(beneficiary_account.vested_amount(1_200).unwrap(), 700);
+    assert_eq!(beneficiary_account.vested_amount(1_499).unwrap(), 999);
+    assert_eq!(beneficiary_account.vested_amount(1_500).unwrap(), 1_000);
+}
+
+#[test]
+pub fn test_grant_is_settled_once_everything_it_will_vest_is_withdrawn() {
+    let mut beneficiary_account = build_beneficiary_account(1_000, 0, 100, 1_000);
+    beneficiary_account.total_withdrawn = 500;
+    assert!(!beneficiary_account.is_settled(2_000).unwrap());
+
+    beneficiary_account.revoke_at = Some(600);
+    assert!(!beneficiary_account.is_settled(2_000).unwrap());
+
+    beneficiary_account.total_withdrawn = 600;
+    assert!(beneficiary_account.is_settled(2_000).unwrap());
+}
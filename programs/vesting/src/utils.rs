@@ -0,0 +1,230 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{
+        allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer,
+    },
+};
+use anchor_spl::token_interface::{
+    close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TransferChecked,
+};
+
+use crate::{
+    error::CustomError,
+    events::{AdminActionExecuted, TokensClaimed},
+    state::{AdminAction, BeneficiaryAccount, QueuedAdminAction, VestingAccount},
+};
+
+/// Moves `amount` out of the treasury PDA, signing with its seeds.
+pub fn transfer_from_treasury<'info>(
+    token_program: AccountInfo<'info>,
+    cpi_accounts: TransferChecked<'info>,
+    vesting_account: Pubkey,
+    treasury_bump: u8,
+    amount: u64,
+    decimals: u8,
+) -> Result<()> {
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"vesting_treasury",
+        vesting_account.as_ref(),
+        &[treasury_bump],
+    ]];
+
+    let cpi_context = CpiContext::new_with_signer(token_program, cpi_accounts, signer_seeds);
+    transfer_checked(cpi_context, amount, decimals)
+}
+
+/// Pays out everything currently claimable on a grant into `destination_token_account`.
+pub fn claim_vested_tokens_into<'info>(
+    vesting_account: &mut Account<'info, VestingAccount>,
+    beneficiary_vesting_account: &mut Account<'info, BeneficiaryAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    treasury_token_account: &mut InterfaceAccount<'info, TokenAccount>,
+    destination_token_account: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        beneficiary_vesting_account.vested_amount(now)? > 0,
+        CustomError::ClaimNotAvailableYet
+    );
+
+    let claimable_amount = beneficiary_vesting_account.claimable_amount(now)?;
+    require!(claimable_amount > 0, CustomError::NothingToClaim);
+
+    let destination_token_account_key = destination_token_account.key();
+    let transfer_token_cpi_account = TransferChecked {
+        mint: mint.to_account_info(),
+        from: treasury_token_account.to_account_info(),
+        to: destination_token_account,
+        authority: treasury_token_account.to_account_info(),
+    };
+    let vesting_account_key = vesting_account.key();
+
+    transfer_from_treasury(
+        token_program,
+        transfer_token_cpi_account,
+        vesting_account_key,
+        vesting_account.treasury_bump,
+        claimable_amount,
+        mint.decimals,
+    )?;
+    beneficiary_vesting_account.record_withdrawal(claimable_amount)?;
+    vesting_account.release_obligation(claimable_amount)?;
+
+    treasury_token_account.reload()?;
+    vesting_account.assert_treasury_covers_obligation(treasury_token_account.amount)?;
+
+    emit!(TokensClaimed {
+        beneficiary: beneficiary_vesting_account.beneficiary.key(),
+        vesting_account: vesting_account_key,
+        grant_id: beneficiary_vesting_account.grant_id,
+        destination_token_account: destination_token_account_key,
+        timestamp: now,
+        amount_claimed: claimable_amount
+    });
+
+    Ok(())
+}
+
+/// Closes the treasury PDA token account, signing with its seeds.
+pub fn close_treasury<'info>(
+    token_program: AccountInfo<'info>,
+    cpi_accounts: CloseAccount<'info>,
+    vesting_account: Pubkey,
+    treasury_bump: u8,
+) -> Result<()> {
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"vesting_treasury",
+        vesting_account.as_ref(),
+        &[treasury_bump],
+    ]];
+
+    close_account(CpiContext::new_with_signer(
+        token_program,
+        cpi_accounts,
+        signer_seeds,
+    ))
+}
+
+/// Creates an account owned by this program at the PDA signed for by `signer_seeds`,
+/// still succeeding if someone has already sent lamports to the address.
+pub fn create_program_account<'info>(
+    payer: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let signer_seeds = &[signer_seeds];
+    let rent = Rent::get()?.minimum_balance(space);
+    if account.lamports() == 0 {
+        return create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                CreateAccount {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+                signer_seeds,
+            ),
+            rent,
+            space as u64,
+            &crate::ID,
+        );
+    }
+
+    let top_up = rent.saturating_sub(account.lamports());
+    if top_up > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate {
+                account_to_allocate: account.clone(),
+            },
+            signer_seeds,
+        ),
+        space as u64,
+    )?;
+    assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Assign {
+                account_to_assign: account.clone(),
+            },
+            signer_seeds,
+        ),
+        &crate::ID,
+    )
+}
+
+/// Closes an account owned by this program that is not part of the instruction's
+/// typed accounts, sending its rent to `destination`.
+pub fn close_program_account<'info>(
+    account: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+) -> Result<()> {
+    let lamports = destination
+        .lamports()
+        .checked_add(account.lamports())
+        .ok_or(CustomError::MathOverflow)?;
+    **destination.try_borrow_mut_lamports()? = lamports;
+    **account.try_borrow_mut_lamports()? = 0;
+
+    account.assign(&System::id());
+    account.realloc(0, false).map_err(Into::into)
+}
+
+/// Lets a sensitive `action` through immediately when no delay is configured,
+/// otherwise only via a matching queued action that has waited out the current delay.
+/// Actions that target a grant need its `beneficiary_vesting_account`.
+pub fn consume_queued_admin_action(
+    vesting_account: &mut Account<VestingAccount>,
+    queued_admin_action: Option<&Account<QueuedAdminAction>>,
+    action: AdminAction,
+    now: i64,
+    beneficiary_vesting_account: Option<&mut Account<BeneficiaryAccount>>,
+) -> Result<()> {
+    let Some(queued_admin_action) = queued_admin_action else {
+        require!(
+            vesting_account.admin_action_delay == 0,
+            CustomError::AdminActionNotQueued
+        );
+        return Ok(());
+    };
+
+    require!(
+        queued_admin_action.action == action,
+        CustomError::AdminActionMismatch
+    );
+    let execute_after = queued_admin_action
+        .queued_at
+        .checked_add(vesting_account.admin_action_delay)
+        .ok_or(CustomError::MathOverflow)?;
+    require!(now >= execute_after, CustomError::AdminActionNotReady);
+    vesting_account.open_admin_action_count = vesting_account
+        .open_admin_action_count
+        .checked_sub(1)
+        .ok_or(CustomError::MathOverflow)?;
+    if let Some(beneficiary_vesting_account) = beneficiary_vesting_account {
+        beneficiary_vesting_account.remove_admin_action()?;
+    }
+
+    emit!(AdminActionExecuted {
+        vesting_account: vesting_account.key(),
+        queued_admin_action: queued_admin_action.key(),
+        action
+    });
+    Ok(())
+}
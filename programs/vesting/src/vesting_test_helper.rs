@@ -4,13 +4,23 @@ use anchor_lang::prelude::*;
 use anchor_lang::{
     prelude::borsh::BorshSerialize, system_program, AnchorDeserialize, AnchorSerialize,
 };
+use anchor_spl::associated_token::get_associated_token_address;
 use litesvm::LiteSVM;
 use solana_instruction::{AccountMeta, Instruction};
 use solana_keypair::Keypair;
-use solana_kite::{create_token_mint, deploy_program};
+use solana_kite::{
+    create_associated_token_account, create_token_mint, deploy_program, mint_tokens_to_account,
+    send_transaction_from_instructions, SolanaKiteError,
+};
 use solana_pubkey::Pubkey;
 use solana_signer::Signer;
 
+use crate::{
+    error::CustomError,
+    instructions::{AmendVestingScheduleParams, VestingScheduleParams},
+    state::{AdminAction, BeneficiaryAccount, ProposalAccountMeta, ScheduleKind},
+};
+
 pub const PROGRAM_ID: &str = "FJBXuvApEoWjHNF4x4KBUMJmRuBmYyhUpcUT4etKR8iZ";
 pub const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
 
@@ -30,15 +40,13 @@ pub fn setup_vesting_test() -> VestingTestEnvironment {
 
     let employer = Keypair::new();
     litesvm
-        .airdrop(&employer.pubkey(), 1 * LAMPORTS_PER_SOL)
+        .airdrop(&employer.pubkey(), LAMPORTS_PER_SOL)
         .unwrap();
 
     let token_mint = create_token_mint(&mut litesvm, &employer, 9).unwrap();
 
     let worker = Keypair::new();
-    litesvm
-        .airdrop(&worker.pubkey(), 1 * LAMPORTS_PER_SOL)
-        .unwrap();
+    litesvm.airdrop(&worker.pubkey(), LAMPORTS_PER_SOL).unwrap();
 
     VestingTestEnvironment {
         litesvm,
@@ -54,7 +62,7 @@ pub fn get_program_id() -> Pubkey {
 }
 
 thread_local! {
-    static VESTING_ACCOUNT_ID_COUNTER: Cell<u64> = Cell::new(1);
+    static VESTING_ACCOUNT_ID_COUNTER: Cell<u64> = const { Cell::new(1) };
 }
 
 pub fn generate_vesting_account_id() -> u64 {
@@ -65,9 +73,88 @@ pub fn generate_vesting_account_id() -> u64 {
     })
 }
 
+pub fn get_vesting_account_address(
+    program_id: &Pubkey,
+    company_name: &str,
+    vesting_account_id: u64,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"vesting_account",
+            company_name.as_bytes(),
+            &vesting_account_id.to_le_bytes(),
+        ],
+        program_id,
+    )
+    .0
+}
+
+pub fn get_treasury_token_account_address(program_id: &Pubkey, vesting_account: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"vesting_treasury", vesting_account.as_ref()], program_id).0
+}
+
+pub fn get_beneficiary_vesting_account_address(
+    program_id: &Pubkey,
+    beneficiary: &Pubkey,
+    vesting_account: &Pubkey,
+    grant_id: u64,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"beneficiary_vesting_account",
+            beneficiary.as_ref(),
+            vesting_account.as_ref(),
+            &grant_id.to_le_bytes(),
+        ],
+        program_id,
+    )
+    .0
+}
+
+pub fn get_queued_admin_action_address(
+    program_id: &Pubkey,
+    vesting_account: &Pubkey,
+    index: u64,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"queued_admin_action",
+            vesting_account.as_ref(),
+            &index.to_le_bytes(),
+        ],
+        program_id,
+    )
+    .0
+}
+
+/// Anchor reads the program id in place of an omitted optional account.
+pub fn optional_account_meta(account: Option<Pubkey>) -> AccountMeta {
+    match account {
+        Some(account) => AccountMeta::new(account, false),
+        None => AccountMeta::new_readonly(get_program_id(), false),
+    }
+}
+
+pub fn get_instruction_discriminator(instruction_name: &str) -> Vec<u8> {
+    let discriminator_input = format!("global:{instruction_name}");
+    anchor_lang::solana_program::hash::hash(discriminator_input.as_bytes()).to_bytes()[..8].to_vec()
+}
+
 pub fn get_initialize_vesting_discriminator() -> Vec<u8> {
-    let discriminator_input = b"global:create_vesting_account";
-    anchor_lang::solana_program::hash::hash(discriminator_input).to_bytes()[..8].to_vec()
+    get_instruction_discriminator("create_vesting_account")
+}
+
+/// Returns true when `result` failed with the given program error.
+pub fn is_program_error<T>(
+    result: &std::result::Result<T, SolanaKiteError>,
+    error: CustomError,
+) -> bool {
+    match result {
+        Err(SolanaKiteError::TransactionFailed(message)) => {
+            message.contains(&format!("Custom({})", u32::from(error)))
+        }
+        _ => false,
+    }
 }
 
 pub struct InitializeVestingAccounts {
@@ -131,3 +218,653 @@ pub fn build_initialize_vesting_instruction(
         data: instruction_data,
     }
 }
+
+pub struct TransferToTreasuryAccounts {
+    pub funder: Pubkey,
+    pub funder_ata: Pubkey,
+    pub mint: Pubkey,
+    pub vesting_account: Pubkey,
+    pub treasury_token_account: Pubkey,
+    pub token_program: Pubkey,
+}
+
+pub fn build_transfer_to_treasury_instruction(
+    amount: u64,
+    accounts: TransferToTreasuryAccounts,
+) -> Instruction {
+    let mut instruction_data = get_instruction_discriminator("transfer_tokens_to_treasury");
+    amount.serialize(&mut instruction_data).unwrap();
+
+    let account_metas = vec![
+        AccountMeta::new(accounts.funder, true),
+        AccountMeta::new(accounts.funder_ata, false),
+        AccountMeta::new_readonly(accounts.mint, false),
+        AccountMeta::new(accounts.vesting_account, false),
+        AccountMeta::new(accounts.treasury_token_account, false),
+        AccountMeta::new_readonly(accounts.token_program, false),
+    ];
+
+    Instruction {
+        program_id: get_program_id(),
+        accounts: account_metas,
+        data: instruction_data,
+    }
+}
+
+pub struct InitializeVestingScheduleAccounts {
+    pub scheduler: Pubkey,
+    pub vesting_account: Pubkey,
+    pub mint: Pubkey,
+    pub beneficiary: Pubkey,
+    pub beneficiary_vesting_account: Pubkey,
+    pub treasury_token_account: Pubkey,
+    pub system_program: Pubkey,
+}
+
+pub fn build_initialize_vesting_schedule_instruction(
+    grant_id: u64,
+    params: VestingScheduleParams,
+    accounts: InitializeVestingScheduleAccounts,
+) -> Instruction {
+    let mut instruction_data = get_instruction_discriminator("initialize_vesting_schedule");
+    grant_id.serialize(&mut instruction_data).unwrap();
+    params.serialize(&mut instruction_data).unwrap();
+
+    let account_metas = vec![
+        AccountMeta::new(accounts.scheduler, true),
+        AccountMeta::new(accounts.vesting_account, false),
+        AccountMeta::new_readonly(accounts.mint, false),
+        AccountMeta::new_readonly(accounts.beneficiary, false),
+        AccountMeta::new(accounts.beneficiary_vesting_account, false),
+        AccountMeta::new_readonly(accounts.treasury_token_account, false),
+        AccountMeta::new_readonly(accounts.system_program, false),
+    ];
+
+    Instruction {
+        program_id: get_program_id(),
+        accounts: account_metas,
+        data: instruction_data,
+    }
+}
+
+pub struct RevokeBeneficiaryAccounts {
+    pub revoker: Pubkey,
+    pub beneficiary: Pubkey,
+    pub vesting_account: Pubkey,
+    pub mint: Pubkey,
+    pub treasury_token_account: Pubkey,
+    pub refund_token_account: Pubkey,
+    pub beneficiary_vesting_account: Pubkey,
+    pub queued_admin_action: Option<Pubkey>,
+    pub token_program: Pubkey,
+}
+
+pub fn build_revoke_beneficiary_instruction(accounts: RevokeBeneficiaryAccounts) -> Instruction {
+    let instruction_data = get_instruction_discriminator("revoke_beneficiary_account");
+
+    let account_metas = vec![
+        AccountMeta::new(accounts.revoker, true),
+        AccountMeta::new_readonly(accounts.beneficiary, false),
+        AccountMeta::new(accounts.vesting_account, false),
+        AccountMeta::new_readonly(accounts.mint, false),
+        AccountMeta::new(accounts.treasury_token_account, false),
+        AccountMeta::new(accounts.refund_token_account, false),
+        AccountMeta::new(accounts.beneficiary_vesting_account, false),
+        optional_account_meta(accounts.queued_admin_action),
+        AccountMeta::new_readonly(accounts.token_program, false),
+    ];
+
+    Instruction {
+        program_id: get_program_id(),
+        accounts: account_metas,
+        data: instruction_data,
+    }
+}
+
+pub struct AmendVestingScheduleAccounts {
+    pub scheduler: Pubkey,
+    pub beneficiary: Pubkey,
+    pub vesting_account: Pubkey,
+    pub treasury_token_account: Pubkey,
+    pub beneficiary_vesting_account: Pubkey,
+    pub queued_admin_action: Option<Pubkey>,
+}
+
+pub fn build_amend_vesting_schedule_instruction(
+    params: AmendVestingScheduleParams,
+    accounts: AmendVestingScheduleAccounts,
+) -> Instruction {
+    let mut instruction_data = get_instruction_discriminator("amend_vesting_schedule");
+    params.serialize(&mut instruction_data).unwrap();
+
+    let account_metas = vec![
+        AccountMeta::new(accounts.scheduler, true),
+        AccountMeta::new_readonly(accounts.beneficiary, false),
+        AccountMeta::new(accounts.vesting_account, false),
+        AccountMeta::new_readonly(accounts.treasury_token_account, false),
+        AccountMeta::new(accounts.beneficiary_vesting_account, false),
+        optional_account_meta(accounts.queued_admin_action),
+    ];
+
+    Instruction {
+        program_id: get_program_id(),
+        accounts: account_metas,
+        data: instruction_data,
+    }
+}
+
+pub fn build_claim_vested_tokens_instruction(
+    beneficiary: Pubkey,
+    mint: Pubkey,
+    vesting_account: Pubkey,
+    treasury_token_account: Pubkey,
+    beneficiary_vesting_account: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: get_program_id(),
+        accounts: vec![
+            AccountMeta::new(beneficiary, true),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new(get_associated_token_address(&beneficiary, &mint), false),
+            AccountMeta::new(vesting_account, false),
+            AccountMeta::new(treasury_token_account, false),
+            AccountMeta::new(beneficiary_vesting_account, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(anchor_spl::associated_token::ID, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: get_instruction_discriminator("claim_vested_tokens"),
+    }
+}
+
+pub fn build_suspend_beneficiary_instruction(
+    revoker: Pubkey,
+    beneficiary: Pubkey,
+    vesting_account: Pubkey,
+    beneficiary_vesting_account: Pubkey,
+    queued_admin_action: Option<Pubkey>,
+) -> Instruction {
+    Instruction {
+        program_id: get_program_id(),
+        accounts: vec![
+            AccountMeta::new(revoker, true),
+            AccountMeta::new_readonly(beneficiary, false),
+            AccountMeta::new(vesting_account, false),
+            AccountMeta::new(beneficiary_vesting_account, false),
+            optional_account_meta(queued_admin_action),
+        ],
+        data: get_instruction_discriminator("suspend_beneficiary_account"),
+    }
+}
+
+pub fn build_resume_beneficiary_instruction(
+    revoker: Pubkey,
+    beneficiary: Pubkey,
+    vesting_account: Pubkey,
+    beneficiary_vesting_account: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: get_program_id(),
+        accounts: vec![
+            AccountMeta::new_readonly(revoker, true),
+            AccountMeta::new_readonly(beneficiary, false),
+            AccountMeta::new_readonly(vesting_account, false),
+            AccountMeta::new(beneficiary_vesting_account, false),
+        ],
+        data: get_instruction_discriminator("resume_beneficiary_account"),
+    }
+}
+
+pub fn build_propose_admin_instruction(
+    admin: Pubkey,
+    vesting_account: Pubkey,
+    new_admin: Pubkey,
+    queued_admin_action: Option<Pubkey>,
+) -> Instruction {
+    Instruction {
+        program_id: get_program_id(),
+        accounts: vec![
+            AccountMeta::new(admin, true),
+            AccountMeta::new(vesting_account, false),
+            AccountMeta::new_readonly(new_admin, false),
+            optional_account_meta(queued_admin_action),
+        ],
+        data: get_instruction_discriminator("propose_admin"),
+    }
+}
+
+pub fn build_accept_admin_instruction(new_admin: Pubkey, vesting_account: Pubkey) -> Instruction {
+    Instruction {
+        program_id: get_program_id(),
+        accounts: vec![
+            AccountMeta::new(new_admin, true),
+            AccountMeta::new(vesting_account, false),
+        ],
+        data: get_instruction_discriminator("accept_admin"),
+    }
+}
+
+pub fn build_set_admin_action_delay_instruction(
+    admin: Pubkey,
+    vesting_account: Pubkey,
+    delay: i64,
+    queued_admin_action: Option<Pubkey>,
+) -> Instruction {
+    let mut instruction_data = get_instruction_discriminator("set_admin_action_delay");
+    delay.serialize(&mut instruction_data).unwrap();
+
+    Instruction {
+        program_id: get_program_id(),
+        accounts: vec![
+            AccountMeta::new(admin, true),
+            AccountMeta::new(vesting_account, false),
+            optional_account_meta(queued_admin_action),
+        ],
+        data: instruction_data,
+    }
+}
+
+pub fn build_queue_admin_action_instruction(
+    authority: Pubkey,
+    vesting_account: Pubkey,
+    index: u64,
+    action: AdminAction,
+) -> Instruction {
+    let program_id = get_program_id();
+    let mut instruction_data = get_instruction_discriminator("queue_admin_action");
+    action.serialize(&mut instruction_data).unwrap();
+
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(authority, true),
+            AccountMeta::new(vesting_account, false),
+            AccountMeta::new(
+                get_queued_admin_action_address(&program_id, &vesting_account, index),
+                false,
+            ),
+            optional_account_meta(action.beneficiary_vesting_account()),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: instruction_data,
+    }
+}
+
+pub fn build_cancel_admin_action_instruction(
+    authority: Pubkey,
+    vesting_account: Pubkey,
+    queued_by: Pubkey,
+    queued_admin_action: Pubkey,
+    beneficiary_vesting_account: Option<Pubkey>,
+) -> Instruction {
+    Instruction {
+        program_id: get_program_id(),
+        accounts: vec![
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(vesting_account, false),
+            AccountMeta::new(queued_by, false),
+            AccountMeta::new(queued_admin_action, false),
+            optional_account_meta(beneficiary_vesting_account),
+        ],
+        data: get_instruction_discriminator("cancel_admin_action"),
+    }
+}
+
+pub fn build_transfer_grant_instruction(
+    beneficiary: Pubkey,
+    new_beneficiary: Pubkey,
+    approver: Option<Pubkey>,
+    vesting_account: Pubkey,
+    rent_payer: Pubkey,
+    beneficiary_vesting_account: Pubkey,
+    grant_id: u64,
+) -> Instruction {
+    let program_id = get_program_id();
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(beneficiary, true),
+            AccountMeta::new_readonly(new_beneficiary, false),
+            match approver {
+                Some(approver) => AccountMeta::new_readonly(approver, true),
+                None => AccountMeta::new_readonly(program_id, false),
+            },
+            AccountMeta::new_readonly(vesting_account, false),
+            AccountMeta::new(rent_payer, false),
+            AccountMeta::new(beneficiary_vesting_account, false),
+            AccountMeta::new(
+                get_beneficiary_vesting_account_address(
+                    &program_id,
+                    &new_beneficiary,
+                    &vesting_account,
+                    grant_id,
+                ),
+                false,
+            ),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: get_instruction_discriminator("transfer_grant"),
+    }
+}
+
+pub fn build_close_vesting_account_instruction(
+    admin: Pubkey,
+    vesting_account: Pubkey,
+    mint: Pubkey,
+    treasury_token_account: Pubkey,
+) -> Instruction {
+    let program_id = get_program_id();
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(admin, true),
+            AccountMeta::new(vesting_account, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new(treasury_token_account, false),
+            AccountMeta::new(get_associated_token_address(&admin, &mint), false),
+            AccountMeta::new(get_multisig_address(&program_id, &vesting_account), false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+        ],
+        data: get_instruction_discriminator("close_vesting_account"),
+    }
+}
+
+pub fn get_multisig_address(program_id: &Pubkey, vesting_account: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"multisig", vesting_account.as_ref()], program_id).0
+}
+
+pub fn get_multisig_authority_address(program_id: &Pubkey, multisig: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"multisig_authority", multisig.as_ref()], program_id).0
+}
+
+pub fn get_multisig_proposal_address(program_id: &Pubkey, multisig: &Pubkey, index: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"multisig_proposal",
+            multisig.as_ref(),
+            &index.to_le_bytes(),
+        ],
+        program_id,
+    )
+    .0
+}
+
+pub fn build_create_multisig_instruction(
+    admin: Pubkey,
+    vesting_account: Pubkey,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Instruction {
+    let program_id = get_program_id();
+    let multisig = get_multisig_address(&program_id, &vesting_account);
+    let multisig_authority = get_multisig_authority_address(&program_id, &multisig);
+
+    let mut instruction_data = get_instruction_discriminator("create_multisig");
+    signers.serialize(&mut instruction_data).unwrap();
+    threshold.serialize(&mut instruction_data).unwrap();
+
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(admin, true),
+            AccountMeta::new_readonly(vesting_account, false),
+            AccountMeta::new(multisig, false),
+            AccountMeta::new_readonly(multisig_authority, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: instruction_data,
+    }
+}
+
+/// Wraps `proposed` in a multisig proposal at `index`.
+pub fn build_create_multisig_proposal_instruction(
+    proposer: Pubkey,
+    multisig: Pubkey,
+    index: u64,
+    proposed: &Instruction,
+) -> Instruction {
+    let program_id = get_program_id();
+    let instruction_accounts: Vec<ProposalAccountMeta> = proposed
+        .accounts
+        .iter()
+        .map(|meta| ProposalAccountMeta {
+            pubkey: meta.pubkey,
+            is_signer: meta.is_signer,
+            is_writable: meta.is_writable,
+        })
+        .collect();
+
+    let mut instruction_data = get_instruction_discriminator("create_multisig_proposal");
+    instruction_accounts
+        .serialize(&mut instruction_data)
+        .unwrap();
+    proposed.data.serialize(&mut instruction_data).unwrap();
+
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(proposer, true),
+            AccountMeta::new(multisig, false),
+            AccountMeta::new(
+                get_multisig_proposal_address(&program_id, &multisig, index),
+                false,
+            ),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: instruction_data,
+    }
+}
+
+pub fn build_approve_multisig_proposal_instruction(
+    signer: Pubkey,
+    multisig: Pubkey,
+    proposal: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: get_program_id(),
+        accounts: vec![
+            AccountMeta::new_readonly(signer, true),
+            AccountMeta::new_readonly(multisig, false),
+            AccountMeta::new(proposal, false),
+        ],
+        data: get_instruction_discriminator("approve_multisig_proposal"),
+    }
+}
+
+/// Executes `proposal`, passing the accounts of the `proposed` instruction as remaining
+/// accounts. The multisig authority is signed for by the program, not the transaction.
+pub fn build_execute_multisig_proposal_instruction(
+    executor: Pubkey,
+    multisig: Pubkey,
+    proposal: Pubkey,
+    proposer: Pubkey,
+    proposed: &Instruction,
+) -> Instruction {
+    let program_id = get_program_id();
+    let mut account_metas = vec![
+        AccountMeta::new_readonly(executor, true),
+        AccountMeta::new_readonly(multisig, false),
+        AccountMeta::new(proposal, false),
+        AccountMeta::new(proposer, false),
+    ];
+    account_metas.extend(proposed.accounts.iter().map(|meta| AccountMeta {
+        pubkey: meta.pubkey,
+        is_signer: false,
+        is_writable: meta.is_writable,
+    }));
+    account_metas.push(AccountMeta::new_readonly(program_id, false));
+
+    Instruction {
+        program_id,
+        accounts: account_metas,
+        data: get_instruction_discriminator("execute_multisig_proposal"),
+    }
+}
+
+pub fn build_update_multisig_instruction(
+    multisig: Pubkey,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Instruction {
+    let program_id = get_program_id();
+    let mut instruction_data = get_instruction_discriminator("update_multisig");
+    signers.serialize(&mut instruction_data).unwrap();
+    threshold.serialize(&mut instruction_data).unwrap();
+
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(get_multisig_authority_address(&program_id, &multisig), true),
+            AccountMeta::new(multisig, false),
+        ],
+        data: instruction_data,
+    }
+}
+
+pub struct FundedVestingAccount {
+    pub vesting_account: Pubkey,
+    pub treasury_token_account: Pubkey,
+}
+
+/// Creates a vesting account for the employer and funds its treasury with `amount`.
+pub fn create_funded_vesting_account(
+    test_environment: &mut VestingTestEnvironment,
+    company_name: &str,
+    amount: u64,
+) -> FundedVestingAccount {
+    let program_id = test_environment.program_id;
+    let employer = test_environment.employer.pubkey();
+    let mint = test_environment.token_mint;
+
+    let vesting_id = generate_vesting_account_id();
+    let vesting_account = get_vesting_account_address(&program_id, company_name, vesting_id);
+    let treasury_token_account = get_treasury_token_account_address(&program_id, &vesting_account);
+
+    let employer_ata = create_associated_token_account(
+        &mut test_environment.litesvm,
+        &test_environment.employer,
+        &mint,
+        &test_environment.employer,
+    )
+    .unwrap();
+    mint_tokens_to_account(
+        &mut test_environment.litesvm,
+        &mint,
+        &employer_ata,
+        amount,
+        &test_environment.employer,
+    )
+    .unwrap();
+
+    let create_vesting_instruction = build_initialize_vesting_instruction(
+        company_name.to_string(),
+        vesting_id,
+        build_initialize_vesting_accounts(employer, mint, vesting_account, treasury_token_account),
+    );
+    let transfer_to_treasury_instruction = build_transfer_to_treasury_instruction(
+        amount,
+        TransferToTreasuryAccounts {
+            funder: employer,
+            funder_ata: employer_ata,
+            mint,
+            vesting_account,
+            treasury_token_account,
+            token_program: spl_token::ID,
+        },
+    );
+
+    send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![create_vesting_instruction, transfer_to_treasury_instruction],
+        &[&test_environment.employer],
+        &employer,
+    )
+    .unwrap();
+
+    FundedVestingAccount {
+        vesting_account,
+        treasury_token_account,
+    }
+}
+
+/// Gives `beneficiary` a linear grant of `total_amount` vesting over [0, 1_000] with a
+/// cliff at 100, and returns its beneficiary account address.
+pub fn create_linear_grant(
+    test_environment: &mut VestingTestEnvironment,
+    funded_vesting_account: &FundedVestingAccount,
+    beneficiary: Pubkey,
+    total_amount: u64,
+) -> Pubkey {
+    let program_id = test_environment.program_id;
+    let employer = test_environment.employer.pubkey();
+    let beneficiary_vesting_account = get_beneficiary_vesting_account_address(
+        &program_id,
+        &beneficiary,
+        &funded_vesting_account.vesting_account,
+        0,
+    );
+
+    let initialize_schedule_instruction = build_initialize_vesting_schedule_instruction(
+        0,
+        VestingScheduleParams {
+            start_time: 0,
+            end_time: 1_000,
+            total_amount,
+            cliff_time: 100,
+            cliff_amount: 0,
+            initial_unlock_amount: 0,
+            schedule_kind: ScheduleKind::Linear,
+            unlock_checkpoints: vec![],
+        },
+        InitializeVestingScheduleAccounts {
+            scheduler: employer,
+            vesting_account: funded_vesting_account.vesting_account,
+            mint: test_environment.token_mint,
+            beneficiary,
+            beneficiary_vesting_account,
+            treasury_token_account: funded_vesting_account.treasury_token_account,
+            system_program: system_program::ID,
+        },
+    );
+    send_transaction_from_instructions(
+        &mut test_environment.litesvm,
+        vec![initialize_schedule_instruction],
+        &[&test_environment.employer],
+        &employer,
+    )
+    .unwrap();
+
+    beneficiary_vesting_account
+}
+
+pub const SECONDS_PER_YEAR: i64 = 365 * 24 * 60 * 60;
+
+/// Builds an in-memory schedule for exercising the vesting math directly.
+pub fn build_beneficiary_account(
+    total_amount: u64,
+    start_time: i64,
+    cliff_time: i64,
+    end_time: i64,
+) -> BeneficiaryAccount {
+    BeneficiaryAccount {
+        beneficiary: Pubkey::new_unique(),
+        vesting_account: Pubkey::new_unique(),
+        grant_id: 0,
+        start_time,
+        end_time,
+        total_amount,
+        total_withdrawn: 0,
+        cliff_time,
+        cliff_amount: 0,
+        initial_unlock_amount: 0,
+        revoke_at: None,
+        suspended_at: None,
+        suspended_seconds: 0,
+        schedule_kind: ScheduleKind::Linear,
+        rent_payer: Pubkey::new_unique(),
+        crank_opt_out: false,
+        open_admin_action_count: 0,
+        bump: 0,
+        unlock_checkpoints: vec![],
+    }
+}
@@ -33,6 +33,10 @@ describe('vesting', () => {
   const VESTING_MIDDLE_TIME_AFTER_CLIFF = 500;
   const VESTING_ACCOUNT_ID = new BN(1);
   const ASSIGNED_AMOUNT_TO_BENEFICIARY = new BN(10);
+  const LINEAR_SCHEDULE = { linear: {} };
+  const NO_CLIFF_AMOUNT = new BN(0);
+  const NO_INITIAL_UNLOCK_AMOUNT = new BN(0);
+  const GRANT_ID = new BN(0);
 
   let provider: BankrunProvider;
   let context: ProgramTestContext;
@@ -132,6 +136,7 @@ describe('vesting', () => {
         Buffer.from('beneficiary_vesting_account'),
         beneficiary.publicKey.toBuffer(),
         vestingAccount.toBuffer(),
+        GRANT_ID.toArrayLike(Buffer, 'le', U64_BYTES),
       ],
       programId
     );
@@ -223,7 +228,16 @@ describe('vesting', () => {
     const cliffTime = new BN(VESTING_CLIFF_TIME);
 
     await program.methods
-      .initializeVestingSchedule(startTime, endTime, totalAmount, cliffTime)
+      .initializeVestingSchedule(GRANT_ID, {
+        startTime,
+        endTime,
+        totalAmount,
+        cliffTime,
+        cliffAmount: NO_CLIFF_AMOUNT,
+        initialUnlockAmount: NO_INITIAL_UNLOCK_AMOUNT,
+        scheduleKind: LINEAR_SCHEDULE,
+        unlockCheckpoints: [],
+      })
       .accounts({
         vestingAccount,
         mint,
@@ -249,7 +263,16 @@ describe('vesting', () => {
 
     try {
       await program.methods
-        .initializeVestingSchedule(startTime, endTime, totalAmount, cliffTime)
+        .initializeVestingSchedule(GRANT_ID, {
+          startTime,
+          endTime,
+          totalAmount,
+          cliffTime,
+          cliffAmount: NO_CLIFF_AMOUNT,
+          initialUnlockAmount: NO_INITIAL_UNLOCK_AMOUNT,
+          scheduleKind: LINEAR_SCHEDULE,
+          unlockCheckpoints: [],
+        })
         .accounts({
           vestingAccount,
           mint,
@@ -270,7 +293,16 @@ describe('vesting', () => {
 
     try {
       await program.methods
-        .initializeVestingSchedule(startTime, endTime, totalAmount, cliffTime)
+        .initializeVestingSchedule(GRANT_ID, {
+          startTime,
+          endTime,
+          totalAmount,
+          cliffTime,
+          cliffAmount: NO_CLIFF_AMOUNT,
+          initialUnlockAmount: NO_INITIAL_UNLOCK_AMOUNT,
+          scheduleKind: LINEAR_SCHEDULE,
+          unlockCheckpoints: [],
+        })
         .accounts({
           vestingAccount,
           mint,
@@ -292,7 +324,16 @@ describe('vesting', () => {
     const cliffTime = new BN(VESTING_CLIFF_TIME);
     try {
       await program.methods
-        .initializeVestingSchedule(startTime, endTime, totalAmount, cliffTime)
+        .initializeVestingSchedule(GRANT_ID, {
+          startTime,
+          endTime,
+          totalAmount,
+          cliffTime,
+          cliffAmount: NO_CLIFF_AMOUNT,
+          initialUnlockAmount: NO_INITIAL_UNLOCK_AMOUNT,
+          scheduleKind: LINEAR_SCHEDULE,
+          unlockCheckpoints: [],
+        })
         .accounts({
           vestingAccount,
           mint,
@@ -304,51 +345,242 @@ describe('vesting', () => {
     }
   });
 
-  it('admin can change admin', async () => {
+  it('Initialize Vesting fails with a zero vesting period', async () => {
+    const tempAccount = new Keypair();
+    const startTime = new BN(VESTING_START_TIME);
+    const endTime = new BN(VESTING_END_TIME);
+    const totalAmount = new BN(ASSIGNED_AMOUNT_TO_BENEFICIARY).mul(
+      LAMPORTS_PER_MINT_TOKEN
+    );
+    const cliffTime = new BN(VESTING_CLIFF_TIME);
+    try {
+      await program.methods
+        .initializeVestingSchedule(GRANT_ID, {
+          startTime,
+          endTime,
+          totalAmount,
+          cliffTime,
+          cliffAmount: NO_CLIFF_AMOUNT,
+          initialUnlockAmount: NO_INITIAL_UNLOCK_AMOUNT,
+          scheduleKind: { periodic: { periodSeconds: new BN(0) } },
+          unlockCheckpoints: [],
+        })
+        .accounts({
+          vestingAccount,
+          mint,
+          beneficiary: tempAccount.publicKey,
+        })
+        .rpc({ commitment: 'confirmed', skipPreflight: false });
+      expect.fail('should have thrown');
+    } catch (error) {
+      expect(error.toString()).to.include('InvalidVestingPeriod');
+    }
+  });
+
+  it('Initialize Vesting fails if unlock checkpoints do not reach the total amount', async () => {
+    const tempAccount = new Keypair();
+    const totalAmount = new BN(ASSIGNED_AMOUNT_TO_BENEFICIARY).mul(
+      LAMPORTS_PER_MINT_TOKEN
+    );
+    try {
+      await program.methods
+        .initializeVestingSchedule(GRANT_ID, {
+          startTime: new BN(VESTING_START_TIME),
+          endTime: new BN(VESTING_END_TIME),
+          totalAmount,
+          cliffTime: new BN(VESTING_CLIFF_TIME),
+          cliffAmount: NO_CLIFF_AMOUNT,
+          initialUnlockAmount: NO_INITIAL_UNLOCK_AMOUNT,
+          scheduleKind: { piecewise: {} },
+          unlockCheckpoints: [
+            {
+              timestamp: new BN(VESTING_CLIFF_TIME),
+              cumulativeAmount: totalAmount.divn(2),
+            },
+          ],
+        })
+        .accounts({
+          vestingAccount,
+          mint,
+          beneficiary: tempAccount.publicKey,
+        })
+        .rpc({ commitment: 'confirmed', skipPreflight: false });
+      expect.fail('should have thrown');
+    } catch (error) {
+      expect(error.toString()).to.include('InvalidUnlockCheckpoints');
+    }
+  });
+
+  it('admin can hand over admin through propose and accept', async () => {
     const temporaryAdmin = beneficiary;
     await program.methods
-      .changeAdmin()
+      .proposeAdmin()
       .accounts({
         vestingAccount,
         newAdmin: temporaryAdmin.publicKey,
+        queuedAdminAction: null,
       })
       .rpc({ commitment: 'confirmed', skipPreflight: true });
 
-    let admin = (await program.account.vestingAccount.fetch(vestingAccount))
-      .admin;
+    let vestingAccountData = await program.account.vestingAccount.fetch(
+      vestingAccount
+    );
+    expect(vestingAccountData.admin.toBase58()).equal(
+      employer.publicKey.toBase58()
+    );
+    expect(vestingAccountData.pendingAdmin.toBase58()).equal(
+      temporaryAdmin.publicKey.toBase58()
+    );
+
+    await beneficiaryProgram.methods
+      .acceptAdmin()
+      .accounts({
+        newAdmin: temporaryAdmin.publicKey,
+        vestingAccount,
+      })
+      .signers([temporaryAdmin])
+      .rpc({ commitment: 'confirmed', skipPreflight: true });
 
-    expect(admin.toBase58()).equal(temporaryAdmin.publicKey.toBase58());
+    vestingAccountData = await program.account.vestingAccount.fetch(
+      vestingAccount
+    );
+    expect(vestingAccountData.admin.toBase58()).equal(
+      temporaryAdmin.publicKey.toBase58()
+    );
+    expect(vestingAccountData.pendingAdmin).to.be.null;
 
     await beneficiaryProgram.methods
-      .changeAdmin()
+      .proposeAdmin()
       .accounts({
         admin: temporaryAdmin.publicKey,
         vestingAccount,
         newAdmin: employer.publicKey,
+        queuedAdminAction: null,
       })
       .signers([temporaryAdmin])
       .rpc({ commitment: 'confirmed', skipPreflight: true });
 
-    admin = (await program.account.vestingAccount.fetch(vestingAccount)).admin;
+    await program.methods
+      .acceptAdmin()
+      .accounts({
+        newAdmin: employer.publicKey,
+        vestingAccount,
+      })
+      .rpc({ commitment: 'confirmed', skipPreflight: true });
+
+    const admin = (await program.account.vestingAccount.fetch(vestingAccount))
+      .admin;
 
     expect(admin.toBase58()).equal(employer.publicKey.toBase58());
   });
 
-  it('Admin Change fail if not done by admin', async () => {
+  it('Admin proposal fails if not done by admin', async () => {
     const tempAccount = new Keypair();
     try {
       await beneficiaryProgram.methods
-        .changeAdmin()
+        .proposeAdmin()
         .accounts({
           admin: beneficiary.publicKey,
           vestingAccount,
           newAdmin: tempAccount.publicKey,
+          queuedAdminAction: null,
+        })
+        .signers([beneficiary])
+        .rpc({ commitment: 'confirmed', skipPreflight: true });
+      expect.fail('should have thrown');
+    } catch (error) {
+      expect(error.toString()).to.includes('UnAuthorized');
+    }
+  });
+
+  it('Only the proposed admin can accept, and the admin can cancel', async () => {
+    const proposedAdmin = new Keypair();
+    await program.methods
+      .proposeAdmin()
+      .accounts({
+        vestingAccount,
+        newAdmin: proposedAdmin.publicKey,
+        queuedAdminAction: null,
+      })
+      .rpc({ commitment: 'confirmed', skipPreflight: true });
+
+    try {
+      await beneficiaryProgram.methods
+        .acceptAdmin()
+        .accounts({
+          newAdmin: beneficiary.publicKey,
+          vestingAccount,
         })
         .signers([beneficiary])
         .rpc({ commitment: 'confirmed', skipPreflight: true });
+      expect.fail('should have thrown');
+    } catch (error) {
+      expect(error.toString()).to.includes('UnAuthorized');
+    }
+
+    await program.methods
+      .cancelAdminProposal()
+      .accounts({ vestingAccount })
+      .rpc({ commitment: 'confirmed', skipPreflight: true });
+
+    const vestingAccountData = await program.account.vestingAccount.fetch(
+      vestingAccount
+    );
+    expect(vestingAccountData.pendingAdmin).to.be.null;
+    expect(vestingAccountData.admin.toBase58()).equal(
+      employer.publicKey.toBase58()
+    );
+  });
+
+  it('Admin can assign roles and non-admins cannot', async () => {
+    await program.methods
+      .setRoles(
+        beneficiary.publicKey,
+        beneficiary.publicKey,
+        employer.publicKey
+      )
+      .accounts({ vestingAccount })
+      .rpc({ commitment: 'confirmed', skipPreflight: true });
+
+    let vestingAccountData = await program.account.vestingAccount.fetch(
+      vestingAccount
+    );
+    expect(vestingAccountData.scheduler.toBase58()).equal(
+      beneficiary.publicKey.toBase58()
+    );
+    expect(vestingAccountData.revoker.toBase58()).equal(
+      beneficiary.publicKey.toBase58()
+    );
+    expect(vestingAccountData.treasurer.toBase58()).equal(
+      employer.publicKey.toBase58()
+    );
+
+    try {
+      await beneficiaryProgram.methods
+        .setRoles(
+          beneficiary.publicKey,
+          beneficiary.publicKey,
+          beneficiary.publicKey
+        )
+        .accounts({ admin: beneficiary.publicKey, vestingAccount })
+        .signers([beneficiary])
+        .rpc({ commitment: 'confirmed', skipPreflight: true });
+      expect.fail('should have thrown');
     } catch (error) {
       expect(error.toString()).to.includes('UnAuthorized');
     }
+
+    await program.methods
+      .setRoles(employer.publicKey, employer.publicKey, employer.publicKey)
+      .accounts({ vestingAccount })
+      .rpc({ commitment: 'confirmed', skipPreflight: true });
+
+    vestingAccountData = await program.account.vestingAccount.fetch(
+      vestingAccount
+    );
+    expect(vestingAccountData.treasurer.toBase58()).equal(
+      employer.publicKey.toBase58()
+    );
   });
 
   it('user can claim tokens linearly', async () => {
@@ -364,7 +596,7 @@ describe('vesting', () => {
     );
 
     await beneficiaryProgram.methods
-      .claimVestedTokens(COMPANY_NAME, VESTING_ACCOUNT_ID)
+      .claimVestedTokens()
       .accounts({
         beneficiary: beneficiary.publicKey,
         mint,
@@ -391,12 +623,21 @@ describe('vesting', () => {
         .div(new BN(VESTING_END_TIME))
         .toString()
     );
+
+    const vestingAccountData = await program.account.vestingAccount.fetch(
+      vestingAccount
+    );
+    expect(vestingAccountData.totalTokenObligation.toString()).equal(
+      ASSIGNED_AMOUNT_TO_BENEFICIARY.mul(LAMPORTS_PER_MINT_TOKEN)
+        .sub(new BN(tokenAccountData.amount.toString()))
+        .toString()
+    );
   });
 
   it('token claims fails when there is nothing to claim', async () => {
     try {
       await beneficiaryProgram.methods
-        .claimVestedTokens(COMPANY_NAME, VESTING_ACCOUNT_ID)
+        .claimVestedTokens()
         .accounts({
           beneficiary: beneficiary.publicKey,
           mint,
@@ -412,14 +653,144 @@ describe('vesting', () => {
     }
   });
 
+  it('Claims are rejected while the vesting account is paused', async () => {
+    await program.methods
+      .pauseVestingAccount()
+      .accounts({ vestingAccount })
+      .rpc({ commitment: 'confirmed', skipPreflight: true });
+
+    try {
+      await beneficiaryProgram.methods
+        .claimVestedTokens()
+        .accounts({
+          beneficiary: beneficiary.publicKey,
+          mint,
+          vestingAccount,
+          treasuryTokenAccount,
+          beneficiaryVestingAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([beneficiary])
+        .rpc({ commitment: 'confirmed', skipPreflight: true });
+      expect.fail('should have thrown');
+    } catch (error) {
+      expect(error.toString()).to.includes('VestingAccountIsPaused');
+    }
+
+    await program.methods
+      .unpauseVestingAccount()
+      .accounts({ vestingAccount })
+      .rpc({ commitment: 'confirmed', skipPreflight: true });
+
+    const vestingAccountData = await program.account.vestingAccount.fetch(
+      vestingAccount
+    );
+    expect(vestingAccountData.paused).equal(false);
+  });
+
+  it('Treasury outflows are rejected while the vesting account is paused', async () => {
+    const expectPaused = async (call: Promise<string>) => {
+      try {
+        await call;
+        expect.fail('should have thrown');
+      } catch (error) {
+        expect(error.toString()).to.includes('VestingAccountIsPaused');
+      }
+    };
+
+    await program.methods
+      .pauseVestingAccount()
+      .accounts({ vestingAccount })
+      .rpc({ commitment: 'confirmed', skipPreflight: true });
+
+    await expectPaused(
+      program.methods
+        .revokeBeneficiaryAccount()
+        .accounts({
+          beneficiary: beneficiary.publicKey,
+          vestingAccount,
+          mint,
+          beneficiaryVestingAccount,
+          refundTokenAccount: employer_ata,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          queuedAdminAction: null,
+        })
+        .rpc({ commitment: 'confirmed', skipPreflight: true })
+    );
+    await expectPaused(
+      program.methods
+        .withdrawTreasurySurplus()
+        .accounts({
+          vestingAccount,
+          mint,
+          destinationTokenAccount: employer_ata,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          queuedAdminAction: null,
+        })
+        .rpc({ commitment: 'confirmed', skipPreflight: true })
+    );
+    await expectPaused(
+      program.methods
+        .closeVestingAccount()
+        .accounts({
+          vestingAccount,
+          mint,
+          adminTokenAccount: employer_ata,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc({ commitment: 'confirmed', skipPreflight: true })
+    );
+    await expectPaused(
+      beneficiaryProgram.methods
+        .claimVestedTokensTo()
+        .accounts({
+          beneficiary: beneficiary.publicKey,
+          mint,
+          destinationTokenAccount: employer_ata,
+          vestingAccount,
+          treasuryTokenAccount,
+          beneficiaryVestingAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([beneficiary])
+        .rpc({ commitment: 'confirmed', skipPreflight: true })
+    );
+    await expectPaused(
+      program.methods
+        .crankClaimVestedTokens()
+        .accounts({
+          beneficiary: beneficiary.publicKey,
+          mint,
+          vestingAccount,
+          treasuryTokenAccount,
+          beneficiaryVestingAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc({ commitment: 'confirmed', skipPreflight: true })
+    );
+
+    await program.methods
+      .unpauseVestingAccount()
+      .accounts({ vestingAccount })
+      .rpc({ commitment: 'confirmed', skipPreflight: true });
+  });
+
   it('Admin can revoke a beneficiary Vesting', async () => {
     const clock = await banksClient.getClock();
+    const employerBalanceBefore = AccountLayout.decode(
+      (await banksClient.getAccount(employer_ata))!.data
+    ).amount;
 
     await program.methods
       .revokeBeneficiaryAccount()
       .accounts({
         beneficiary: beneficiary.publicKey,
         vestingAccount,
+        mint,
+        beneficiaryVestingAccount,
+        refundTokenAccount: employer_ata,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        queuedAdminAction: null,
       })
       .rpc();
 
@@ -429,6 +800,50 @@ describe('vesting', () => {
     expect(beneficiaryVestingAccountData.revokeAt.toString()).equal(
       clock.unixTimestamp.toString()
     );
+
+    const totalAmount = ASSIGNED_AMOUNT_TO_BENEFICIARY.mul(
+      LAMPORTS_PER_MINT_TOKEN
+    );
+    const vestedAmount = totalAmount
+      .mul(new BN(clock.unixTimestamp.toString()))
+      .div(new BN(VESTING_END_TIME));
+    const unvestedAmount = totalAmount.sub(vestedAmount);
+
+    const employerBalanceAfter = AccountLayout.decode(
+      (await banksClient.getAccount(employer_ata))!.data
+    ).amount;
+    expect(
+      new BN(employerBalanceAfter.toString())
+        .sub(new BN(employerBalanceBefore.toString()))
+        .toString()
+    ).equal(unvestedAmount.toString());
+
+    const vestingAccountData = await program.account.vestingAccount.fetch(
+      vestingAccount
+    );
+    expect(vestingAccountData.totalTokenObligation.toString()).equal(
+      vestedAmount.sub(beneficiaryVestingAccountData.totalWithdrawn).toString()
+    );
+  });
+
+  it('Revoking twice fails', async () => {
+    try {
+      await program.methods
+        .revokeBeneficiaryAccount()
+        .accounts({
+          beneficiary: beneficiary.publicKey,
+          vestingAccount,
+          mint,
+          beneficiaryVestingAccount,
+          refundTokenAccount: employer_ata,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          queuedAdminAction: null,
+        })
+        .rpc();
+      expect.fail('should have thrown');
+    } catch (error) {
+      expect(error.toString()).to.includes('AlreadyRevoked');
+    }
   });
 
   it('Beneficiary claiming fails after revoking, cant claim anything after that point', async () => {
@@ -445,7 +860,7 @@ describe('vesting', () => {
 
     try {
       await beneficiaryProgram.methods
-        .claimVestedTokens(COMPANY_NAME, VESTING_ACCOUNT_ID)
+        .claimVestedTokens()
         .accounts({
           beneficiary: beneficiary.publicKey,
           mint,
@@ -460,4 +875,903 @@ describe('vesting', () => {
       expect(error.toString()).to.includes('NothingToClaim');
     }
   });
+
+  it('Admin can issue a second independent grant to the same beneficiary', async () => {
+    const secondGrantId = GRANT_ID.addn(1);
+    const [secondBeneficiaryVestingAccount] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('beneficiary_vesting_account'),
+        beneficiary.publicKey.toBuffer(),
+        vestingAccount.toBuffer(),
+        secondGrantId.toArrayLike(Buffer, 'le', U64_BYTES),
+      ],
+      programId
+    );
+
+    await program.methods
+      .initializeVestingSchedule(secondGrantId, {
+        startTime: new BN(VESTING_START_TIME),
+        endTime: new BN(VESTING_END_TIME),
+        totalAmount: ASSIGNED_AMOUNT_TO_BENEFICIARY.mul(
+          LAMPORTS_PER_MINT_TOKEN
+        ),
+        cliffTime: new BN(VESTING_CLIFF_TIME),
+        cliffAmount: NO_CLIFF_AMOUNT,
+        initialUnlockAmount: NO_INITIAL_UNLOCK_AMOUNT,
+        scheduleKind: LINEAR_SCHEDULE,
+        unlockCheckpoints: [],
+      })
+      .accounts({
+        vestingAccount,
+        mint,
+        beneficiary: beneficiary.publicKey,
+      })
+      .rpc({ commitment: 'confirmed', skipPreflight: false });
+
+    const secondGrantData = await program.account.beneficiaryAccount.fetch(
+      secondBeneficiaryVestingAccount
+    );
+    const firstGrantData = await program.account.beneficiaryAccount.fetch(
+      beneficiaryVestingAccount
+    );
+
+    expect(secondGrantData.grantId.toString()).equal(secondGrantId.toString());
+    expect(secondGrantData.revokeAt).to.be.null;
+    expect(firstGrantData.revokeAt).not.to.be.null;
+  });
+
+  it('Beneficiary can batch claim across grants and mints in one instruction', async () => {
+    const secondGrantId = GRANT_ID.addn(1);
+    const [secondBeneficiaryVestingAccount] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('beneficiary_vesting_account'),
+        beneficiary.publicKey.toBuffer(),
+        vestingAccount.toBuffer(),
+        secondGrantId.toArrayLike(Buffer, 'le', U64_BYTES),
+      ],
+      programId
+    );
+    const beneficiary_ata = getAssociatedTokenAddressSync(
+      mint,
+      beneficiary.publicKey,
+      false
+    );
+    const otherMint = await createMint(
+      // @ts-ignore
+      banksClient,
+      employer,
+      employer.publicKey,
+      null,
+      decimals
+    );
+    const otherEmployerAta = await createAssociatedTokenAccount(
+      // @ts-ignore
+      banksClient,
+      employer,
+      otherMint,
+      employer.publicKey
+    );
+    const otherGrantAmount = LAMPORTS_PER_MINT_TOKEN;
+    await mintTo(
+      // @ts-ignore
+      banksClient,
+      employer,
+      otherMint,
+      otherEmployerAta,
+      employer,
+      otherGrantAmount
+    );
+
+    const otherVestingAccountId = VESTING_ACCOUNT_ID.addn(2);
+    const [otherVestingAccount] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('vesting_account'),
+        Buffer.from(COMPANY_NAME),
+        otherVestingAccountId.toArrayLike(Buffer, 'le', U64_BYTES),
+      ],
+      programId
+    );
+    const [otherTreasuryTokenAccount] = PublicKey.findProgramAddressSync(
+      [Buffer.from('vesting_treasury'), otherVestingAccount.toBuffer()],
+      programId
+    );
+    const [otherBeneficiaryVestingAccount] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('beneficiary_vesting_account'),
+        beneficiary.publicKey.toBuffer(),
+        otherVestingAccount.toBuffer(),
+        GRANT_ID.toArrayLike(Buffer, 'le', U64_BYTES),
+      ],
+      programId
+    );
+    const otherBeneficiaryAta = getAssociatedTokenAddressSync(
+      otherMint,
+      beneficiary.publicKey,
+      false
+    );
+
+    await program.methods
+      .createVestingAccount(otherVestingAccountId, COMPANY_NAME)
+      .accounts({ mint: otherMint, tokenProgram: TOKEN_PROGRAM_ID })
+      .rpc({ commitment: 'confirmed', skipPreflight: false });
+    await program.methods
+      .transferTokensToTreasury(otherGrantAmount)
+      .accounts({
+        funder: employer.publicKey,
+        mint: otherMint,
+        vestingAccount: otherVestingAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc({ commitment: 'confirmed', skipPreflight: false });
+    await program.methods
+      .initializeVestingSchedule(GRANT_ID, {
+        startTime: new BN(VESTING_START_TIME),
+        endTime: new BN(VESTING_END_TIME),
+        totalAmount: otherGrantAmount,
+        cliffTime: new BN(VESTING_START_TIME),
+        cliffAmount: NO_CLIFF_AMOUNT,
+        initialUnlockAmount: NO_INITIAL_UNLOCK_AMOUNT,
+        scheduleKind: LINEAR_SCHEDULE,
+        unlockCheckpoints: [],
+      })
+      .accounts({
+        vestingAccount: otherVestingAccount,
+        mint: otherMint,
+        beneficiary: beneficiary.publicKey,
+      })
+      .rpc({ commitment: 'confirmed', skipPreflight: false });
+
+    const balanceBefore = AccountLayout.decode(
+      (await banksClient.getAccount(beneficiary_ata))!.data
+    ).amount;
+
+    const claimGroup = (
+      grantVestingAccount: PublicKey,
+      grantTreasuryTokenAccount: PublicKey,
+      grantBeneficiaryVestingAccount: PublicKey,
+      grantMint: PublicKey,
+      grantBeneficiaryAta: PublicKey
+    ) => [
+      { pubkey: grantVestingAccount, isWritable: true, isSigner: false },
+      { pubkey: grantTreasuryTokenAccount, isWritable: true, isSigner: false },
+      {
+        pubkey: grantBeneficiaryVestingAccount,
+        isWritable: true,
+        isSigner: false,
+      },
+      { pubkey: grantMint, isWritable: false, isSigner: false },
+      { pubkey: grantBeneficiaryAta, isWritable: true, isSigner: false },
+    ];
+
+    await beneficiaryProgram.methods
+      .batchClaimVestedTokens()
+      .accounts({
+        beneficiary: beneficiary.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .remainingAccounts([
+        ...claimGroup(
+          vestingAccount,
+          treasuryTokenAccount,
+          beneficiaryVestingAccount,
+          mint,
+          beneficiary_ata
+        ),
+        ...claimGroup(
+          vestingAccount,
+          treasuryTokenAccount,
+          secondBeneficiaryVestingAccount,
+          mint,
+          beneficiary_ata
+        ),
+        ...claimGroup(
+          otherVestingAccount,
+          otherTreasuryTokenAccount,
+          otherBeneficiaryVestingAccount,
+          otherMint,
+          otherBeneficiaryAta
+        ),
+      ])
+      .signers([beneficiary])
+      .rpc({ commitment: 'confirmed', skipPreflight: true });
+
+    const balanceAfter = AccountLayout.decode(
+      (await banksClient.getAccount(beneficiary_ata))!.data
+    ).amount;
+    expect(
+      new BN(balanceAfter.toString())
+        .sub(new BN(balanceBefore.toString()))
+        .toString()
+    ).equal(
+      ASSIGNED_AMOUNT_TO_BENEFICIARY.mul(LAMPORTS_PER_MINT_TOKEN).toString()
+    );
+    const otherBalance = AccountLayout.decode(
+      (await banksClient.getAccount(otherBeneficiaryAta))!.data
+    ).amount;
+    expect(otherBalance.toString()).equal(otherGrantAmount.toString());
+
+    const secondGrantData = await program.account.beneficiaryAccount.fetch(
+      secondBeneficiaryVestingAccount
+    );
+    expect(secondGrantData.totalWithdrawn.toString()).equal(
+      secondGrantData.totalAmount.toString()
+    );
+  });
+
+  it('Closing a grant fails while tokens remain or when a stranger signs', async () => {
+    const unsettledGrantId = GRANT_ID.addn(5);
+    const [unsettledBeneficiaryVestingAccount] =
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('beneficiary_vesting_account'),
+          beneficiary.publicKey.toBuffer(),
+          vestingAccount.toBuffer(),
+          unsettledGrantId.toArrayLike(Buffer, 'le', U64_BYTES),
+        ],
+        programId
+      );
+    const grantAmount = LAMPORTS_PER_MINT_TOKEN;
+    const stranger = new Keypair();
+
+    await program.methods
+      .transferTokensToTreasury(grantAmount)
+      .accounts({
+        funder: employer.publicKey,
+        mint,
+        vestingAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc({ commitment: 'confirmed', skipPreflight: false });
+    await program.methods
+      .initializeVestingSchedule(unsettledGrantId, {
+        startTime: new BN(VESTING_END_TIME * 2),
+        endTime: new BN(VESTING_END_TIME * 3),
+        totalAmount: grantAmount,
+        cliffTime: new BN(VESTING_END_TIME * 2),
+        cliffAmount: NO_CLIFF_AMOUNT,
+        initialUnlockAmount: NO_INITIAL_UNLOCK_AMOUNT,
+        scheduleKind: LINEAR_SCHEDULE,
+        unlockCheckpoints: [],
+      })
+      .accounts({
+        vestingAccount,
+        mint,
+        beneficiary: beneficiary.publicKey,
+      })
+      .rpc({ commitment: 'confirmed', skipPreflight: false });
+
+    try {
+      await beneficiaryProgram.methods
+        .closeBeneficiaryAccount()
+        .accounts({
+          authority: beneficiary.publicKey,
+          beneficiary: beneficiary.publicKey,
+          vestingAccount,
+          rentPayer: employer.publicKey,
+          beneficiaryVestingAccount: unsettledBeneficiaryVestingAccount,
+        })
+        .signers([beneficiary])
+        .rpc({ commitment: 'confirmed', skipPreflight: true });
+      expect.fail('should have thrown');
+    } catch (error) {
+      expect(error.toString()).to.includes('GrantNotSettled');
+    }
+
+    try {
+      await program.methods
+        .closeBeneficiaryAccount()
+        .accounts({
+          authority: stranger.publicKey,
+          beneficiary: beneficiary.publicKey,
+          vestingAccount,
+          rentPayer: employer.publicKey,
+          beneficiaryVestingAccount,
+        })
+        .signers([stranger])
+        .rpc({ commitment: 'confirmed', skipPreflight: true });
+      expect.fail('should have thrown');
+    } catch (error) {
+      expect(error.toString()).to.includes('UnAuthorized');
+    }
+
+    await program.methods
+      .cancelVestingSchedule()
+      .accounts({
+        beneficiary: beneficiary.publicKey,
+        vestingAccount,
+        beneficiaryVestingAccount: unsettledBeneficiaryVestingAccount,
+        queuedAdminAction: null,
+      })
+      .rpc({ commitment: 'confirmed', skipPreflight: false });
+  });
+
+  it('Beneficiary can close a settled grant and the rent goes to its payer', async () => {
+    const rent = (await banksClient.getAccount(beneficiaryVestingAccount))!
+      .lamports;
+    const employerLamportsBefore = await banksClient.getBalance(
+      employer.publicKey
+    );
+
+    await beneficiaryProgram.methods
+      .closeBeneficiaryAccount()
+      .accounts({
+        authority: beneficiary.publicKey,
+        beneficiary: beneficiary.publicKey,
+        vestingAccount,
+        rentPayer: employer.publicKey,
+        beneficiaryVestingAccount,
+      })
+      .signers([beneficiary])
+      .rpc({ commitment: 'confirmed', skipPreflight: false });
+
+    expect(
+      await program.account.beneficiaryAccount.fetchNullable(
+        beneficiaryVestingAccount
+      )
+    ).to.be.null;
+    const employerLamportsAfter = await banksClient.getBalance(
+      employer.publicKey
+    );
+    expect(
+      new BN(employerLamportsAfter.toString())
+        .sub(new BN(employerLamportsBefore.toString()))
+        .toString()
+    ).equal(rent.toString());
+  });
+
+  it('Admin can cancel a grant that has not started yet', async () => {
+    const cancelledGrantId = GRANT_ID.addn(2);
+    const [cancelledBeneficiaryVestingAccount] =
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('beneficiary_vesting_account'),
+          beneficiary.publicKey.toBuffer(),
+          vestingAccount.toBuffer(),
+          cancelledGrantId.toArrayLike(Buffer, 'le', U64_BYTES),
+        ],
+        programId
+      );
+    const obligationBefore = (
+      await program.account.vestingAccount.fetch(vestingAccount)
+    ).totalTokenObligation;
+
+    await program.methods
+      .initializeVestingSchedule(cancelledGrantId, {
+        startTime: new BN(VESTING_END_TIME * 2),
+        endTime: new BN(VESTING_END_TIME * 3),
+        totalAmount: ASSIGNED_AMOUNT_TO_BENEFICIARY.mul(
+          LAMPORTS_PER_MINT_TOKEN
+        ),
+        cliffTime: new BN(VESTING_END_TIME * 2),
+        cliffAmount: NO_CLIFF_AMOUNT,
+        initialUnlockAmount: NO_INITIAL_UNLOCK_AMOUNT,
+        scheduleKind: LINEAR_SCHEDULE,
+        unlockCheckpoints: [],
+      })
+      .accounts({
+        vestingAccount,
+        mint,
+        beneficiary: beneficiary.publicKey,
+      })
+      .rpc({ commitment: 'confirmed', skipPreflight: false });
+
+    await program.methods
+      .cancelVestingSchedule()
+      .accounts({
+        beneficiary: beneficiary.publicKey,
+        vestingAccount,
+        beneficiaryVestingAccount: cancelledBeneficiaryVestingAccount,
+        queuedAdminAction: null,
+      })
+      .rpc({ commitment: 'confirmed', skipPreflight: false });
+
+    expect(
+      await program.account.beneficiaryAccount.fetchNullable(
+        cancelledBeneficiaryVestingAccount
+      )
+    ).to.be.null;
+    const vestingAccountData = await program.account.vestingAccount.fetch(
+      vestingAccount
+    );
+    expect(vestingAccountData.totalTokenObligation.toString()).equal(
+      obligationBefore.toString()
+    );
+  });
+
+  it('Cancelling a grant fails once it has started vesting', async () => {
+    const secondGrantId = GRANT_ID.addn(1);
+    const [secondBeneficiaryVestingAccount] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('beneficiary_vesting_account'),
+        beneficiary.publicKey.toBuffer(),
+        vestingAccount.toBuffer(),
+        secondGrantId.toArrayLike(Buffer, 'le', U64_BYTES),
+      ],
+      programId
+    );
+
+    try {
+      await program.methods
+        .cancelVestingSchedule()
+        .accounts({
+          beneficiary: beneficiary.publicKey,
+          vestingAccount,
+          beneficiaryVestingAccount: secondBeneficiaryVestingAccount,
+          queuedAdminAction: null,
+        })
+        .rpc({ commitment: 'confirmed', skipPreflight: true });
+      expect.fail('should have thrown');
+    } catch (error) {
+      expect(error.toString()).to.includes('CancelNotAllowed');
+    }
+  });
+
+  it('Withdrawing treasury surplus fails if not done by treasurer', async () => {
+    try {
+      await beneficiaryProgram.methods
+        .withdrawTreasurySurplus()
+        .accounts({
+          treasurer: beneficiary.publicKey,
+          vestingAccount,
+          mint,
+          destinationTokenAccount: employer_ata,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          queuedAdminAction: null,
+        })
+        .signers([beneficiary])
+        .rpc({ commitment: 'confirmed', skipPreflight: true });
+      expect.fail('should have thrown');
+    } catch (error) {
+      expect(error.toString()).to.includes('UnAuthorized');
+    }
+  });
+
+  it('Admin can withdraw treasury surplus above the obligation', async () => {
+    await program.methods
+      .withdrawTreasurySurplus()
+      .accounts({
+        vestingAccount,
+        mint,
+        destinationTokenAccount: employer_ata,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        queuedAdminAction: null,
+      })
+      .rpc({ commitment: 'confirmed', skipPreflight: false });
+
+    const vestingAccountData = await program.account.vestingAccount.fetch(
+      vestingAccount
+    );
+    const treasuryData = AccountLayout.decode(
+      (await banksClient.getAccount(treasuryTokenAccount))!.data
+    );
+
+    expect(treasuryData.amount.toString()).equal(
+      vestingAccountData.totalTokenObligation.toString()
+    );
+  });
+
+  it('Scheduler can amend a grant but never below what has vested', async () => {
+    const secondGrantId = GRANT_ID.addn(1);
+    const [secondBeneficiaryVestingAccount] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('beneficiary_vesting_account'),
+        beneficiary.publicKey.toBuffer(),
+        vestingAccount.toBuffer(),
+        secondGrantId.toArrayLike(Buffer, 'le', U64_BYTES),
+      ],
+      programId
+    );
+    const grantAmount = ASSIGNED_AMOUNT_TO_BENEFICIARY.mul(
+      LAMPORTS_PER_MINT_TOKEN
+    );
+
+    try {
+      await program.methods
+        .amendVestingSchedule({
+          totalAmount: grantAmount.divn(2),
+          endTime: null,
+          cliffTime: null,
+        })
+        .accounts({
+          beneficiary: beneficiary.publicKey,
+          vestingAccount,
+          beneficiaryVestingAccount: secondBeneficiaryVestingAccount,
+          queuedAdminAction: null,
+        })
+        .rpc({ commitment: 'confirmed', skipPreflight: true });
+      expect.fail('should have thrown');
+    } catch (error) {
+      expect(error.toString()).to.includes('AmendmentReducesVestedAmount');
+    }
+
+    await program.methods
+      .transferTokensToTreasury(grantAmount)
+      .accounts({
+        funder: employer.publicKey,
+        mint,
+        vestingAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc({ commitment: 'confirmed', skipPreflight: false });
+
+    await program.methods
+      .amendVestingSchedule({
+        totalAmount: grantAmount.muln(2),
+        endTime: new BN(VESTING_END_TIME * 2),
+        cliffTime: null,
+      })
+      .accounts({
+        beneficiary: beneficiary.publicKey,
+        vestingAccount,
+        beneficiaryVestingAccount: secondBeneficiaryVestingAccount,
+        queuedAdminAction: null,
+      })
+      .rpc({ commitment: 'confirmed', skipPreflight: false });
+
+    const secondGrantData = await program.account.beneficiaryAccount.fetch(
+      secondBeneficiaryVestingAccount
+    );
+    expect(secondGrantData.totalAmount.toString()).equal(
+      grantAmount.muln(2).toString()
+    );
+    expect(secondGrantData.endTime.toString()).equal(
+      (VESTING_END_TIME * 2).toString()
+    );
+
+    const vestingAccountData = await program.account.vestingAccount.fetch(
+      vestingAccount
+    );
+    expect(vestingAccountData.totalTokenObligation.toString()).equal(
+      secondGrantData.totalAmount.sub(secondGrantData.totalWithdrawn).toString()
+    );
+  });
+
+  it('Admin can close a wound-down vesting account and its treasury', async () => {
+    try {
+      await program.methods
+        .closeVestingAccount()
+        .accounts({
+          vestingAccount,
+          mint,
+          adminTokenAccount: employer_ata,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc({ commitment: 'confirmed', skipPreflight: true });
+      expect.fail('should have thrown');
+    } catch (error) {
+      expect(error.toString()).to.includes('VestingAccountInUse');
+    }
+
+    const retiredVestingAccountId = VESTING_ACCOUNT_ID.addn(1);
+    const [retiredVestingAccount] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('vesting_account'),
+        Buffer.from(COMPANY_NAME),
+        retiredVestingAccountId.toArrayLike(Buffer, 'le', U64_BYTES),
+      ],
+      programId
+    );
+    const [retiredTreasuryTokenAccount] = PublicKey.findProgramAddressSync(
+      [Buffer.from('vesting_treasury'), retiredVestingAccount.toBuffer()],
+      programId
+    );
+    const fundedAmount = LAMPORTS_PER_MINT_TOKEN;
+    const employerBalanceBefore = AccountLayout.decode(
+      (await banksClient.getAccount(employer_ata))!.data
+    ).amount;
+
+    await program.methods
+      .createVestingAccount(retiredVestingAccountId, COMPANY_NAME)
+      .accounts({
+        mint: mint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc({ commitment: 'confirmed', skipPreflight: false });
+    await program.methods
+      .transferTokensToTreasury(fundedAmount)
+      .accounts({
+        funder: employer.publicKey,
+        mint,
+        vestingAccount: retiredVestingAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc({ commitment: 'confirmed', skipPreflight: false });
+
+    await program.methods
+      .closeVestingAccount()
+      .accounts({
+        vestingAccount: retiredVestingAccount,
+        mint,
+        adminTokenAccount: employer_ata,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc({ commitment: 'confirmed', skipPreflight: false });
+
+    expect(await banksClient.getAccount(retiredVestingAccount)).to.be.null;
+    expect(await banksClient.getAccount(retiredTreasuryTokenAccount)).to.be
+      .null;
+    const employerBalanceAfter = AccountLayout.decode(
+      (await banksClient.getAccount(employer_ata))!.data
+    ).amount;
+    expect(employerBalanceAfter.toString()).equal(
+      employerBalanceBefore.toString()
+    );
+  });
+
+  it('Beneficiary can move a grant to a new wallet with admin approval', async () => {
+    const secondGrantId = GRANT_ID.addn(1);
+    const newBeneficiary = new Keypair();
+    const [secondBeneficiaryVestingAccount] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('beneficiary_vesting_account'),
+        beneficiary.publicKey.toBuffer(),
+        vestingAccount.toBuffer(),
+        secondGrantId.toArrayLike(Buffer, 'le', U64_BYTES),
+      ],
+      programId
+    );
+    const [newBeneficiaryVestingAccount] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('beneficiary_vesting_account'),
+        newBeneficiary.publicKey.toBuffer(),
+        vestingAccount.toBuffer(),
+        secondGrantId.toArrayLike(Buffer, 'le', U64_BYTES),
+      ],
+      programId
+    );
+    const grantData = await program.account.beneficiaryAccount.fetch(
+      secondBeneficiaryVestingAccount
+    );
+
+    await program.methods
+      .setGrantTransferApproval(true)
+      .accounts({ vestingAccount })
+      .rpc({ commitment: 'confirmed', skipPreflight: false });
+
+    try {
+      await beneficiaryProgram.methods
+        .transferGrant()
+        .accounts({
+          beneficiary: beneficiary.publicKey,
+          newBeneficiary: newBeneficiary.publicKey,
+          approver: null,
+          vestingAccount,
+          rentPayer: employer.publicKey,
+          beneficiaryVestingAccount: secondBeneficiaryVestingAccount,
+          newBeneficiaryVestingAccount,
+        })
+        .signers([beneficiary])
+        .rpc({ commitment: 'confirmed', skipPreflight: true });
+      expect.fail('should have thrown');
+    } catch (error) {
+      expect(error.toString()).to.includes('GrantTransferNeedsApproval');
+    }
+
+    await beneficiaryProgram.methods
+      .transferGrant()
+      .accounts({
+        beneficiary: beneficiary.publicKey,
+        newBeneficiary: newBeneficiary.publicKey,
+        approver: employer.publicKey,
+        vestingAccount,
+        rentPayer: employer.publicKey,
+        beneficiaryVestingAccount: secondBeneficiaryVestingAccount,
+        newBeneficiaryVestingAccount,
+      })
+      .signers([beneficiary, employer])
+      .rpc({ commitment: 'confirmed', skipPreflight: false });
+
+    expect(
+      await program.account.beneficiaryAccount.fetchNullable(
+        secondBeneficiaryVestingAccount
+      )
+    ).to.be.null;
+    const migratedData = await program.account.beneficiaryAccount.fetch(
+      newBeneficiaryVestingAccount
+    );
+    expect(migratedData.beneficiary.toBase58()).equal(
+      newBeneficiary.publicKey.toBase58()
+    );
+    expect(migratedData.totalAmount.toString()).equal(
+      grantData.totalAmount.toString()
+    );
+    expect(migratedData.totalWithdrawn.toString()).equal(
+      grantData.totalWithdrawn.toString()
+    );
+  });
+
+  it('Beneficiary can claim straight into another token account', async () => {
+    const custodyGrantId = GRANT_ID.addn(3);
+    const [custodyBeneficiaryVestingAccount] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('beneficiary_vesting_account'),
+        beneficiary.publicKey.toBuffer(),
+        vestingAccount.toBuffer(),
+        custodyGrantId.toArrayLike(Buffer, 'le', U64_BYTES),
+      ],
+      programId
+    );
+    const grantAmount = LAMPORTS_PER_MINT_TOKEN;
+
+    await program.methods
+      .transferTokensToTreasury(grantAmount)
+      .accounts({
+        funder: employer.publicKey,
+        mint,
+        vestingAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc({ commitment: 'confirmed', skipPreflight: false });
+    await program.methods
+      .initializeVestingSchedule(custodyGrantId, {
+        startTime: new BN(VESTING_START_TIME),
+        endTime: new BN(VESTING_END_TIME),
+        totalAmount: grantAmount,
+        cliffTime: new BN(VESTING_START_TIME),
+        cliffAmount: NO_CLIFF_AMOUNT,
+        initialUnlockAmount: NO_INITIAL_UNLOCK_AMOUNT,
+        scheduleKind: LINEAR_SCHEDULE,
+        unlockCheckpoints: [],
+      })
+      .accounts({
+        vestingAccount,
+        mint,
+        beneficiary: beneficiary.publicKey,
+      })
+      .rpc({ commitment: 'confirmed', skipPreflight: false });
+
+    try {
+      await beneficiaryProgram.methods
+        .claimVestedTokensTo()
+        .accounts({
+          beneficiary: beneficiary.publicKey,
+          mint,
+          destinationTokenAccount: treasuryTokenAccount,
+          vestingAccount,
+          treasuryTokenAccount,
+          beneficiaryVestingAccount: custodyBeneficiaryVestingAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([beneficiary])
+        .rpc({ commitment: 'confirmed', skipPreflight: true });
+      expect.fail('should have thrown');
+    } catch (error) {
+      expect(error.toString()).to.includes('InvalidDestinationTokenAccount');
+    }
+
+    const custodyBalanceBefore = AccountLayout.decode(
+      (await banksClient.getAccount(employer_ata))!.data
+    ).amount;
+
+    await beneficiaryProgram.methods
+      .claimVestedTokensTo()
+      .accounts({
+        beneficiary: beneficiary.publicKey,
+        mint,
+        destinationTokenAccount: employer_ata,
+        vestingAccount,
+        treasuryTokenAccount,
+        beneficiaryVestingAccount: custodyBeneficiaryVestingAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([beneficiary])
+      .rpc({ commitment: 'confirmed', skipPreflight: false });
+
+    const custodyBalanceAfter = AccountLayout.decode(
+      (await banksClient.getAccount(employer_ata))!.data
+    ).amount;
+    expect(
+      new BN(custodyBalanceAfter.toString())
+        .sub(new BN(custodyBalanceBefore.toString()))
+        .toString()
+    ).equal(grantAmount.toString());
+  });
+
+  it('Anyone can crank a claim into the ATA unless the beneficiary opted out', async () => {
+    const crankedGrantId = GRANT_ID.addn(4);
+    const [crankedBeneficiaryVestingAccount] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('beneficiary_vesting_account'),
+        beneficiary.publicKey.toBuffer(),
+        vestingAccount.toBuffer(),
+        crankedGrantId.toArrayLike(Buffer, 'le', U64_BYTES),
+      ],
+      programId
+    );
+    const beneficiary_ata = getAssociatedTokenAddressSync(
+      mint,
+      beneficiary.publicKey,
+      false
+    );
+    const grantAmount = LAMPORTS_PER_MINT_TOKEN;
+
+    await program.methods
+      .transferTokensToTreasury(grantAmount)
+      .accounts({
+        funder: employer.publicKey,
+        mint,
+        vestingAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc({ commitment: 'confirmed', skipPreflight: false });
+    await program.methods
+      .initializeVestingSchedule(crankedGrantId, {
+        startTime: new BN(VESTING_START_TIME),
+        endTime: new BN(VESTING_END_TIME),
+        totalAmount: grantAmount,
+        cliffTime: new BN(VESTING_START_TIME),
+        cliffAmount: NO_CLIFF_AMOUNT,
+        initialUnlockAmount: NO_INITIAL_UNLOCK_AMOUNT,
+        scheduleKind: LINEAR_SCHEDULE,
+        unlockCheckpoints: [],
+      })
+      .accounts({
+        vestingAccount,
+        mint,
+        beneficiary: beneficiary.publicKey,
+      })
+      .rpc({ commitment: 'confirmed', skipPreflight: false });
+
+    await beneficiaryProgram.methods
+      .setCrankOptOut(true)
+      .accounts({
+        beneficiary: beneficiary.publicKey,
+        vestingAccount,
+        beneficiaryVestingAccount: crankedBeneficiaryVestingAccount,
+      })
+      .signers([beneficiary])
+      .rpc({ commitment: 'confirmed', skipPreflight: false });
+
+    try {
+      await program.methods
+        .crankClaimVestedTokens()
+        .accounts({
+          beneficiary: beneficiary.publicKey,
+          mint,
+          vestingAccount,
+          treasuryTokenAccount,
+          beneficiaryVestingAccount: crankedBeneficiaryVestingAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc({ commitment: 'confirmed', skipPreflight: true });
+      expect.fail('should have thrown');
+    } catch (error) {
+      expect(error.toString()).to.includes('CrankClaimOptedOut');
+    }
+
+    await beneficiaryProgram.methods
+      .setCrankOptOut(false)
+      .accounts({
+        beneficiary: beneficiary.publicKey,
+        vestingAccount,
+        beneficiaryVestingAccount: crankedBeneficiaryVestingAccount,
+      })
+      .signers([beneficiary])
+      .rpc({ commitment: 'confirmed', skipPreflight: false });
+
+    const balanceBefore = AccountLayout.decode(
+      (await banksClient.getAccount(beneficiary_ata))!.data
+    ).amount;
+
+    await program.methods
+      .crankClaimVestedTokens()
+      .accounts({
+        beneficiary: beneficiary.publicKey,
+        mint,
+        vestingAccount,
+        treasuryTokenAccount,
+        beneficiaryVestingAccount: crankedBeneficiaryVestingAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc({ commitment: 'confirmed', skipPreflight: false });
+
+    const balanceAfter = AccountLayout.decode(
+      (await banksClient.getAccount(beneficiary_ata))!.data
+    ).amount;
+    expect(
+      new BN(balanceAfter.toString())
+        .sub(new BN(balanceBefore.toString()))
+        .toString()
+    ).equal(grantAmount.toString());
+  });
 });